@@ -0,0 +1,269 @@
+//! Times each day's parse, part 1 and part 2 on its real input (when there
+//! is one) and on a generated one, and compares the times to a saved
+//! report so that slowdowns show up.
+use std::{collections::HashMap, fmt::Write, process::ExitCode, time::Duration};
+
+use common::Part;
+
+use crate::{days, find_input, generate};
+
+/// What to benchmark and what to do with the results
+#[derive(Debug, Eq, PartialEq)]
+pub struct Options {
+    pub days: Vec<u8>,
+    /// How many times to run each day on each input
+    pub runs: usize,
+    /// Seed for the generated inputs, so that runs can be compared
+    pub seed: u64,
+    /// Where to write the report, `-` being stdout
+    pub report: Option<String>,
+    /// A report from an earlier run to compare against
+    pub baseline: Option<String>,
+    /// How many percent slower than the baseline counts as a regression
+    pub threshold: u32,
+}
+
+/// How long one phase of one day took on one input
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Timing {
+    pub day: u8,
+    /// `real` or `generated`
+    pub input: String,
+    /// `parse`, `part1` or `part2`
+    pub phase: String,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+}
+
+const HEADER: &str = "day,input,phase,runs,min_ns,median_ns";
+
+pub fn run(options: &Options) -> ExitCode {
+    let mut failed = false;
+    let mut timings = Vec::new();
+    for &day in &options.days {
+        let Some(solved) = days::day(day) else {
+            eprintln!("Day {day}: not solved yet");
+            continue;
+        };
+
+        let mut inputs = Vec::new();
+        if let Some(path) = find_input(day) {
+            match std::fs::read_to_string(&path) {
+                Ok(input) => inputs.push(("real", input)),
+                Err(e) => eprintln!("Day {day}: could not read {}: {e}", path.display()),
+            }
+        }
+        if let Some(input) = generate::input(day, options.seed) {
+            inputs.push(("generated", input));
+        }
+        if inputs.is_empty() {
+            eprintln!("Day {day}: no input.txt and no generator, skipping");
+        }
+
+        for (kind, input) in inputs {
+            match time_day(solved.solve, &input, options.runs) {
+                Ok(phases) => {
+                    for (phase, times) in phases {
+                        let timing = Timing::new(day, kind, phase, times);
+                        println!("{timing}");
+                        timings.push(timing);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Day {day}: bad {kind} input: {e}");
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    if let Some(path) = &options.report {
+        let report = to_csv(&timings);
+        if path == "-" {
+            print!("{report}");
+        } else if let Err(e) = std::fs::write(path, report) {
+            eprintln!("error: could not write {path}: {e}");
+            failed = true;
+        }
+    }
+
+    if let Some(path) = &options.baseline {
+        let baseline = match std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|csv| from_csv(&csv))
+        {
+            Ok(baseline) => baseline,
+            Err(e) => {
+                eprintln!("error: could not read the baseline {path}: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let regressions = compare(&baseline, &timings, options.threshold);
+        if regressions > 0 {
+            eprintln!("{regressions} timings are more than {}% slower than {path}", options.threshold);
+            failed = true;
+        }
+    }
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+/// Runs a day `runs` times, collecting how long each phase took every time
+fn time_day(
+    solve: common::Solver,
+    input: &str,
+    runs: usize,
+) -> Result<Vec<(&'static str, Vec<Duration>)>, String> {
+    let mut phases = vec![("parse", Vec::new()), ("part1", Vec::new()), ("part2", Vec::new())];
+    for _ in 0..runs {
+        let run = solve(input, &Part::BOTH)?;
+        phases[0].1.push(run.parse);
+        for answer in run.answers {
+            let phase = match answer.part {
+                Part::One => 1,
+                Part::Two => 2,
+            };
+            phases[phase].1.push(answer.time);
+        }
+    }
+    Ok(phases)
+}
+
+impl Timing {
+    fn new(day: u8, input: &str, phase: &str, mut times: Vec<Duration>) -> Self {
+        times.sort();
+        Timing {
+            day,
+            input: input.to_string(),
+            phase: phase.to_string(),
+            runs: times.len(),
+            min: times.first().copied().unwrap_or_default(),
+            median: times.get(times.len() / 2).copied().unwrap_or_default(),
+        }
+    }
+
+    fn key(&self) -> (u8, &str, &str) {
+        (self.day, &self.input, &self.phase)
+    }
+}
+
+impl std::fmt::Display for Timing {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {:>2} {:<9} {:<5} {:>12} (min {:?} over {} runs)",
+            self.day,
+            self.input,
+            self.phase,
+            format!("{:?}", self.median),
+            self.min,
+            self.runs
+        )
+    }
+}
+
+fn to_csv(timings: &[Timing]) -> String {
+    let mut csv = format!("{HEADER}\n");
+    for t in timings {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            t.day, t.input, t.phase, t.runs, t.min.as_nanos(), t.median.as_nanos()
+        )
+        .unwrap();
+    }
+    csv
+}
+
+fn from_csv(csv: &str) -> Result<Vec<Timing>, String> {
+    let mut lines = csv.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header == HEADER => (),
+        _ => return Err(format!("expected the header `{HEADER}`")),
+    }
+
+    lines
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let bad = || format!("line {}: `{line}` is not a timing", i + 1);
+            let fields: Vec<&str> = line.split(',').collect();
+            let [day, input, phase, runs, min, median] = fields[..] else {
+                return Err(bad());
+            };
+            let nanos = |n: &str| n.parse().map(Duration::from_nanos).map_err(|_| bad());
+            Ok(Timing {
+                day: day.parse().map_err(|_| bad())?,
+                input: input.to_string(),
+                phase: phase.to_string(),
+                runs: runs.parse().map_err(|_| bad())?,
+                min: nanos(min)?,
+                median: nanos(median)?,
+            })
+        })
+        .collect()
+}
+
+/// Prints how every timing changed since the baseline and returns how many
+/// got slower by more than `threshold` percent
+fn compare(baseline: &[Timing], timings: &[Timing], threshold: u32) -> usize {
+    let before: HashMap<_, _> = baseline.iter().map(|t| (t.key(), t.median)).collect();
+    let mut regressions = 0;
+    for timing in timings {
+        let Some(&before) = before.get(&timing.key()) else { continue };
+        let change = percent_change(before, timing.median);
+        let verdict = if change > threshold as f64 {
+            regressions += 1;
+            "  <- regression"
+        } else {
+            ""
+        };
+        println!(
+            "Day {:>2} {:<9} {:<5} {:>12} -> {:>12} ({change:+.1}%){verdict}",
+            timing.day,
+            timing.input,
+            timing.phase,
+            format!("{before:?}"),
+            format!("{:?}", timing.median),
+        );
+    }
+    regressions
+}
+
+fn percent_change(before: Duration, after: Duration) -> f64 {
+    let before = before.as_nanos().max(1) as f64;
+    (after.as_nanos() as f64 - before) / before * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: u8, phase: &str, median: u64) -> Timing {
+        Timing {
+            day,
+            input: "real".to_string(),
+            phase: phase.to_string(),
+            runs: 3,
+            min: Duration::from_nanos(median / 2),
+            median: Duration::from_nanos(median),
+        }
+    }
+
+    #[test]
+    fn report_round_trip() {
+        let timings = vec![timing(1, "parse", 1200), timing(15, "part2", 987_654_321)];
+        assert_eq!(from_csv(&to_csv(&timings)), Ok(timings));
+        assert!(from_csv("day,input\n").is_err());
+        assert!(from_csv(&format!("{HEADER}\n1,real,parse,3,x,5\n")).is_err());
+    }
+
+    #[test]
+    fn regressions() {
+        let baseline = vec![timing(1, "parse", 1000), timing(1, "part1", 1000)];
+        let faster = vec![timing(1, "parse", 900), timing(1, "part1", 1050)];
+        let slower = vec![timing(1, "parse", 1200), timing(2, "part1", 5000)];
+        assert_eq!(compare(&baseline, &faster, 10), 0);
+        assert_eq!(compare(&baseline, &slower, 10), 1);
+    }
+}
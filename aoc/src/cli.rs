@@ -0,0 +1,249 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use common::{Part, Source};
+
+use crate::bench;
+
+pub const USAGE: &str = "\
+Usage: aoc run <DAYS> [--part <1|2>] [--input <PATH|-> | --example] [--json]
+       aoc bench <DAYS> [--runs <N>] [--seed <N>] [--report <PATH|->]
+                        [--baseline <PATH>] [--threshold <PERCENT>]
+       aoc verify <DAYS>
+
+DAYS is a single day (5), a range (1..23 or 1..=23) or a comma separated
+list of either (1,3,10..=12).
+
+Each day reads its input.txt unless --input gives a file (or - for stdin)
+to read instead, which only works for a single day, or --example picks the
+example from each day's puzzle text. --json prints every answer as a
+{day, part, answer, duration_ns} object on a line of its own instead.
+
+bench times parse, part 1 and part 2 separately on each day's input.txt
+and on a generated input, 3 runs each by default. --report writes the
+timings as CSV, and --baseline compares them to an earlier report, failing
+if any got more than --threshold percent (default 10) slower.
+
+verify runs each day on its example, its input.txt and any other input
+named in its answers.txt, and reports whether each part matches the
+answer recorded there, fails, or has no answer recorded yet.
+
+Any command takes -v (or -vv, -vvv...) to have the days print what they
+are up to on stderr.";
+
+/// What the user asked for on the command line
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    /// No `source` means each day's own input.txt
+    Run { days: Vec<u8>, parts: Vec<Part>, source: Option<Source>, json: bool },
+    Bench(bench::Options),
+    Verify { days: Vec<u8> },
+}
+
+impl Command {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        match args.split_first() {
+            Some((command, args)) if command == "run" => parse_run(args),
+            Some((command, args)) if command == "bench" => parse_bench(args),
+            Some((command, args)) if command == "verify" => parse_verify(args),
+            Some((other, _)) => Err(format!("unknown command `{other}`")),
+            None => Err("no command given".to_string()),
+        }
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let mut days = None;
+    let mut parts = Part::BOTH.to_vec();
+    let mut source = None;
+    let mut json = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part needs a value")?;
+                parts = vec![part.parse()?];
+            }
+            "--input" | "-i" if source.is_none() => {
+                let path = args.next().ok_or("--input needs a path")?;
+                source = Some(Source::from_arg(path));
+            }
+            "--example" | "-e" if source.is_none() => source = Some(Source::Example),
+            "--input" | "-i" | "--example" | "-e" => {
+                return Err("only one of --input and --example can be given".to_string())
+            }
+            spec if days.is_none() => days = Some(parse_days(spec)?),
+            other => return Err(format!("unexpected argument `{other}`")),
+        }
+    }
+
+    let days = days.ok_or("no days given")?;
+    if days.len() > 1 && matches!(source, Some(Source::File(_) | Source::Stdin)) {
+        return Err("--input only works for a single day".to_string());
+    }
+    Ok(Command::Run { days, parts, source, json })
+}
+
+fn parse_bench(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let mut days = None;
+    let mut options = bench::Options {
+        days: Vec::new(),
+        runs: 3,
+        seed: 2021,
+        report: None,
+        baseline: None,
+        threshold: 10,
+    };
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next().cloned().ok_or(format!("{flag} needs a value"))
+        };
+        match arg.as_str() {
+            "--runs" => options.runs = number("--runs", value("--runs")?)?,
+            "--seed" => options.seed = number("--seed", value("--seed")?)?,
+            "--threshold" => options.threshold = number("--threshold", value("--threshold")?)?,
+            "--report" => options.report = Some(value("--report")?),
+            "--baseline" => options.baseline = Some(value("--baseline")?),
+            spec if days.is_none() => days = Some(parse_days(spec)?),
+            other => return Err(format!("unexpected argument `{other}`")),
+        }
+    }
+
+    options.days = days.ok_or("no days given")?;
+    if options.runs == 0 {
+        return Err("--runs has to be at least 1".to_string());
+    }
+    Ok(Command::Bench(options))
+}
+
+fn parse_verify(args: &[String]) -> Result<Command, String> {
+    match args {
+        [days] => Ok(Command::Verify { days: parse_days(days)? }),
+        [] => Err("no days given".to_string()),
+        [_, other, ..] => Err(format!("unexpected argument `{other}`")),
+    }
+}
+
+fn number<T: FromStr>(flag: &str, n: String) -> Result<T, String> {
+    n.parse().map_err(|_| format!("{flag} needs a number, not `{n}`"))
+}
+
+fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for item in spec.split(',') {
+        days.extend(parse_range(item)?);
+    }
+    Ok(days)
+}
+
+fn parse_range(item: &str) -> Result<RangeInclusive<u8>, String> {
+    let day = |s: &str| s
+        .trim()
+        .parse::<u8>()
+        .map_err(|_| format!("`{s}` is not a day"));
+
+    if let Some((start, end)) = item.split_once("..=") {
+        Ok(day(start)?..=day(end)?)
+    } else if let Some((start, end)) = item.split_once("..") {
+        let end = day(end)?;
+        if end == 0 {
+            return Err(format!("`{item}` is an empty range"));
+        }
+        Ok(day(start)?..=end - 1)
+    } else {
+        let day = day(item)?;
+        Ok(day..=day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn single_day() {
+        assert_eq!(
+            Command::parse(&args("run 5")),
+            Ok(Command::Run { days: vec![5], parts: vec![Part::One, Part::Two], source: None, json: false })
+        );
+    }
+
+    #[test]
+    fn ranges_and_parts() {
+        assert_eq!(
+            Command::parse(&args("run 1..=3 --part 2")),
+            Ok(Command::Run { days: vec![1, 2, 3], parts: vec![Part::Two], source: None, json: false })
+        );
+        assert_eq!(
+            Command::parse(&args("run 1..3,7 --json")),
+            Ok(Command::Run { days: vec![1, 2, 7], parts: vec![Part::One, Part::Two], source: None, json: true })
+        );
+    }
+
+    #[test]
+    fn input_sources() {
+        assert_eq!(
+            Command::parse(&args("run 5 --input day_5/input.txt")),
+            Ok(Command::Run {
+                days: vec![5],
+                parts: vec![Part::One, Part::Two],
+                source: Some(Source::File("day_5/input.txt".into())),
+                json: false,
+            })
+        );
+        assert_eq!(
+            Command::parse(&args("run 11 -p 1 -i -")),
+            Ok(Command::Run { days: vec![11], parts: vec![Part::One], source: Some(Source::Stdin), json: false })
+        );
+        assert_eq!(
+            Command::parse(&args("run 1..=3 --example")),
+            Ok(Command::Run {
+                days: vec![1, 2, 3],
+                parts: vec![Part::One, Part::Two],
+                source: Some(Source::Example),
+                json: false,
+            })
+        );
+        assert!(Command::parse(&args("run 1..=3 --input -")).is_err());
+        assert!(Command::parse(&args("run 5 --input")).is_err());
+        assert!(Command::parse(&args("run 5 --input - --example")).is_err());
+    }
+
+    #[test]
+    fn bench_options() {
+        assert_eq!(
+            Command::parse(&args("bench 15,23 --runs 10 --baseline bench.csv --threshold 25")),
+            Ok(Command::Bench(bench::Options {
+                days: vec![15, 23],
+                runs: 10,
+                seed: 2021,
+                report: None,
+                baseline: Some("bench.csv".to_string()),
+                threshold: 25,
+            }))
+        );
+        assert!(Command::parse(&args("bench 1 --runs many")).is_err());
+        assert!(Command::parse(&args("bench 1 --runs 0")).is_err());
+        assert!(Command::parse(&args("bench --report -")).is_err());
+    }
+
+    #[test]
+    fn verify_days() {
+        assert_eq!(Command::parse(&args("verify 1..=25")), Ok(Command::Verify { days: (1..=25).collect() }));
+        assert!(Command::parse(&args("verify")).is_err());
+        assert!(Command::parse(&args("verify 1 2")).is_err());
+    }
+
+    #[test]
+    fn bad_arguments() {
+        assert!(Command::parse(&args("run")).is_err());
+        assert!(Command::parse(&args("run five")).is_err());
+        assert!(Command::parse(&args("run 5 --part 3")).is_err());
+        assert!(Command::parse(&args("walk 5")).is_err());
+    }
+}
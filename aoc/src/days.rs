@@ -0,0 +1,34 @@
+use common::Day;
+
+/// Looks up a day of the calendar, if it has been solved yet.
+pub fn day(day: u8) -> Option<Day> {
+    let solved = match day {
+        1 => Day::of::<day_1::Day1>(),
+        2 => Day::of::<day_2::Day2>(),
+        3 => Day::of::<day_3::Day3>(),
+        4 => Day::of::<day_4::Day4>(),
+        5 => Day::of::<day_5::Day5>(),
+        6 => Day::of::<day_6::Day6>(),
+        7 => Day::of::<day_7::Day7>(),
+        8 => Day::of::<day_8::Day8>(),
+        9 => Day::of::<day_9::Day9>(),
+        10 => Day::of::<day_10::Day10>(),
+        11 => Day::of::<day_11::Day11>(),
+        12 => Day::of::<day_12::Day12>(),
+        13 => Day::of::<day_13::Day13>(),
+        14 => Day::of::<day_14::Day14>(),
+        15 => Day::of::<day_15::Day15>(),
+        16 => Day::of::<day_16::Day16>(),
+        17 => Day::of::<day_17::Day17>(),
+        18 => Day::of::<day_18::Day18>(),
+        19 => Day::of::<day_19::Day19>(),
+        20 => Day::of::<day_20::Day20>(),
+        21 => Day::of::<day_21::Day21>(),
+        22 => Day::of::<day_22::Day22>(),
+        23 => Day::of::<day_23::Day23>(),
+        24 => Day::of::<day_24::Day24>(),
+        25 => Day::of::<day_25::Day25>(),
+        _ => return None,
+    };
+    Some(solved)
+}
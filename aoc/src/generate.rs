@@ -0,0 +1,333 @@
+//! Made up puzzle inputs, roughly the size and shape of the real ones, so
+//! that every day can be benchmarked even without its input.txt. The same
+//! seed always makes the same input.
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// A generated input for `day`, if there is a generator for it. Days 11,
+/// 12, 16, 19 and 23 are left out: random inputs for those either have no
+/// answer or take forever to find one.
+pub fn input(day: u8, seed: u64) -> Option<String> {
+    let mut rng = StdRng::seed_from_u64(seed.wrapping_add(day as u64));
+    let rng = &mut rng;
+    let input = match day {
+        1 => depths(rng),
+        2 => course(rng),
+        3 => diagnostic(rng),
+        4 => bingo(rng),
+        5 => vents(rng),
+        6 => numbers(rng, 300, 1..=5),
+        7 => numbers(rng, 1000, 0..=1999),
+        8 => displays(rng),
+        9 => digits(rng, 100, 100, 0..=9),
+        10 => navigation(rng),
+        13 => manual(rng),
+        14 => polymer(rng),
+        15 => digits(rng, 100, 100, 1..=9),
+        17 => target(rng),
+        18 => homework(rng),
+        20 => image(rng),
+        21 => players(rng),
+        22 => reboot(rng),
+        24 => monad(rng),
+        25 => sea_floor(rng),
+        _ => return None,
+    };
+    Some(input)
+}
+
+fn depths(rng: &mut StdRng) -> String {
+    let mut depth: i32 = 150;
+    let mut out = String::new();
+    for _ in 0..2000 {
+        depth = (depth + rng.gen_range(-10..=15)).max(100);
+        writeln!(out, "{depth}").unwrap();
+    }
+    out
+}
+
+fn course(rng: &mut StdRng) -> String {
+    let mut out = String::new();
+    for _ in 0..1000 {
+        let command = ["forward", "down", "up"].choose(rng).unwrap();
+        writeln!(out, "{command} {}", rng.gen_range(1..=9)).unwrap();
+    }
+    out
+}
+
+// Every bit needs a most common value for the power consumption, and the
+// ratings keep the more or less common bit until one number is left, so the
+// numbers have to be picked so that neither rating runs dry first.
+fn diagnostic(rng: &mut StdRng) -> String {
+    let mut all: Vec<u16> = (0..1 << 12).collect();
+    let numbers = loop {
+        all.shuffle(rng);
+        let numbers = &all[..1001];
+        if rating(numbers, true) && rating(numbers, false) {
+            break numbers;
+        }
+    };
+    numbers.iter().map(|n| format!("{n:012b}\n")).collect()
+}
+
+fn rating(numbers: &[u16], most_common: bool) -> bool {
+    let mut left = numbers.to_vec();
+    for bit in (0..12).rev() {
+        let ones = left.iter().filter(|&&n| n >> bit & 1 == 1).count();
+        let keep = (ones * 2 >= left.len()) == most_common;
+        left.retain(|&n| (n >> bit & 1 == 1) == keep);
+        match left.len() {
+            0 => return false,
+            1 => return true,
+            _ => (),
+        }
+    }
+    false
+}
+
+fn bingo(rng: &mut StdRng) -> String {
+    let mut calls: Vec<u32> = (0..100).collect();
+    calls.shuffle(rng);
+    let calls: Vec<String> = calls.iter().map(u32::to_string).collect();
+    let mut out = calls.join(",");
+    out.push('\n');
+    for _ in 0..100 {
+        let mut squares: Vec<u32> = (0..100).collect();
+        squares.shuffle(rng);
+        out.push('\n');
+        for row in squares[..25].chunks(5) {
+            let row: Vec<String> = row.iter().map(|n| format!("{n:>2}")).collect();
+            writeln!(out, "{}", row.join(" ")).unwrap();
+        }
+    }
+    out
+}
+
+fn vents(rng: &mut StdRng) -> String {
+    let mut out = String::new();
+    for _ in 0..500 {
+        let (x1, y1) = (rng.gen_range(10..990), rng.gen_range(10..990));
+        let length: i32 = rng.gen_range(-300..=300);
+        let (x2, y2) = match rng.gen_range(0..3) {
+            0 => (x1 + length, y1),
+            1 => (x1, y1 + length),
+            _ => (x1 + length, y1 + length * [-1, 1].choose(rng).unwrap()),
+        };
+        let (x2, y2) = (x2.clamp(0, 999), y2.clamp(0, 999));
+        // Clamping one end of a diagonal would bend it, so shorten both sides
+        let shortest = (x2 - x1).abs().min((y2 - y1).abs());
+        let (x2, y2) = if x1 != x2 && y1 != y2 {
+            (x1 + shortest * (x2 - x1).signum(), y1 + shortest * (y2 - y1).signum())
+        } else {
+            (x2, y2)
+        };
+        writeln!(out, "{x1},{y1} -> {x2},{y2}").unwrap();
+    }
+    out
+}
+
+fn numbers(rng: &mut StdRng, n: usize, range: std::ops::RangeInclusive<u32>) -> String {
+    let numbers: Vec<String> = (0..n).map(|_| rng.gen_range(range.clone()).to_string()).collect();
+    numbers.join(",") + "\n"
+}
+
+fn displays(rng: &mut StdRng) -> String {
+    const SEGMENTS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+    let mut out = String::new();
+    for _ in 0..200 {
+        let mut wires: Vec<char> = "abcdefg".chars().collect();
+        wires.shuffle(rng);
+        let scramble = |digit: usize, rng: &mut StdRng| {
+            let mut lit: Vec<char> = SEGMENTS[digit]
+                .chars()
+                .map(|c| wires[(c as u8 - b'a') as usize])
+                .collect();
+            lit.shuffle(rng);
+            lit.into_iter().collect::<String>()
+        };
+        let mut patterns: Vec<usize> = (0..10).collect();
+        patterns.shuffle(rng);
+        let patterns: Vec<String> = patterns.into_iter().map(|d| scramble(d, rng)).collect();
+        let outputs: Vec<String> = (0..4).map(|_| scramble(rng.gen_range(0..10), rng)).collect();
+        writeln!(out, "{} | {}", patterns.join(" "), outputs.join(" ")).unwrap();
+    }
+    out
+}
+
+fn digits(rng: &mut StdRng, width: usize, height: usize, range: std::ops::RangeInclusive<u32>) -> String {
+    let mut out = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            out.push(char::from_digit(rng.gen_range(range.clone()), 10).unwrap());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// Chunks that are never more than 20 deep, so that the completion scores
+// still fit in a usize
+fn navigation(rng: &mut StdRng) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    let mut out = String::new();
+    let mut incomplete = 0;
+    for i in 0..100 {
+        let mut open: Vec<usize> = Vec::new();
+        let mut line = String::new();
+        // The middle score needs an odd number of incomplete lines
+        let corrupt = if i == 99 { incomplete % 2 == 1 } else { rng.gen_bool(0.5) };
+        while line.len() < 100 || open.is_empty() {
+            if open.is_empty() || (open.len() < 20 && rng.gen_bool(0.55)) {
+                let kind = rng.gen_range(0..4);
+                open.push(kind);
+                line.push(PAIRS[kind].0);
+            } else {
+                line.push(PAIRS[open.pop().unwrap()].1);
+            }
+        }
+        if corrupt {
+            let kind = open.pop().unwrap();
+            line.push(PAIRS[(kind + rng.gen_range(1..4)) % 4].1);
+        } else {
+            incomplete += 1;
+        }
+        writeln!(out, "{line}").unwrap();
+    }
+    out
+}
+
+fn manual(rng: &mut StdRng) -> String {
+    let mut out = String::new();
+    for _ in 0..800 {
+        writeln!(out, "{},{}", rng.gen_range(0..1311), rng.gen_range(0..895)).unwrap();
+    }
+    out.push_str("1310,894\n\n");
+    for fold in ["x=655", "y=447", "x=327", "y=223", "x=163", "y=111", "x=81", "y=55", "x=40", "y=27", "y=13", "y=6"] {
+        writeln!(out, "fold along {fold}").unwrap();
+    }
+    out
+}
+
+fn polymer(rng: &mut StdRng) -> String {
+    let elements: Vec<char> = "BCFHKNOPSV".chars().collect();
+    let mut out: String = (0..20).map(|_| *elements.choose(rng).unwrap()).collect();
+    out.push_str("\n\n");
+    for &a in &elements {
+        for &b in &elements {
+            writeln!(out, "{a}{b} -> {}", elements.choose(rng).unwrap()).unwrap();
+        }
+    }
+    out
+}
+
+fn target(rng: &mut StdRng) -> String {
+    let x = rng.gen_range(20..200);
+    let y = rng.gen_range(-200..-50);
+    format!(
+        "target area: x={x}..{}, y={y}..{}\n",
+        x + rng.gen_range(10..50),
+        y + rng.gen_range(10..40)
+    )
+}
+
+// Only regular numbers from 0 to 9 and no pairs nested inside four others,
+// like the homework
+fn homework(rng: &mut StdRng) -> String {
+    fn snailfish(rng: &mut StdRng, depth: usize, out: &mut String) {
+        if depth > 0 && (depth == 4 || rng.gen_bool(0.3)) {
+            out.push(char::from_digit(rng.gen_range(0..10), 10).unwrap());
+        } else {
+            out.push('[');
+            snailfish(rng, depth + 1, out);
+            out.push(',');
+            snailfish(rng, depth + 1, out);
+            out.push(']');
+        }
+    }
+
+    let mut out = String::new();
+    for _ in 0..100 {
+        snailfish(rng, 0, &mut out);
+        out.push('\n');
+    }
+    out
+}
+
+// A lit pixel for nothing lit and a dark one for everything lit, so the
+// infinite background flickers like it does in the real inputs
+fn image(rng: &mut StdRng) -> String {
+    let mut out = String::from("#");
+    out.extend((0..510).map(|_| if rng.gen_bool(0.5) { '#' } else { '.' }));
+    out.push_str(".\n\n");
+    for _ in 0..100 {
+        out.extend((0..100).map(|_| if rng.gen_bool(0.5) { '#' } else { '.' }));
+        out.push('\n');
+    }
+    out
+}
+
+fn players(rng: &mut StdRng) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.gen_range(1..=10),
+        rng.gen_range(1..=10)
+    )
+}
+
+// The first twenty steps stay in the initialization area like the real ones
+fn reboot(rng: &mut StdRng) -> String {
+    let mut out = String::new();
+    for i in 0..420 {
+        let (reach, size) = if i < 20 { (50, 1..=40) } else { (100_000, 1_000..=30_000) };
+        let switch = if i < 10 || rng.gen_bool(0.7) { "on" } else { "off" };
+        let mut ranges = Vec::new();
+        for axis in ["x", "y", "z"] {
+            let size = rng.gen_range(size.clone());
+            let start = rng.gen_range(-reach..=reach - size);
+            ranges.push(format!("{axis}={start}..{}", start + size));
+        }
+        writeln!(out, "{switch} {}", ranges.join(",")).unwrap();
+    }
+    out
+}
+
+// Seven blocks that push a digit onto z in base 26 and seven that pop one
+// back off, nested like brackets, with each pop only matching when its digit
+// is within 8 of the pushed one, so there is always a model number
+fn monad(rng: &mut StdRng) -> String {
+    let mut pushes = 7;
+    let mut stack = Vec::new();
+    let mut out = String::new();
+    for _ in 0..14 {
+        let push = pushes > 0 && (stack.is_empty() || rng.gen_bool(0.5));
+        let (div, check, offset) = if push {
+            pushes -= 1;
+            let offset = rng.gen_range(1..=16);
+            stack.push(offset);
+            (1, rng.gen_range(10..=15), offset)
+        } else {
+            let pushed = stack.pop().unwrap();
+            (26, rng.gen_range(-8..=8) - pushed, rng.gen_range(1..=16))
+        };
+        writeln!(
+            out,
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {div}\nadd x {check}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {offset}\n\
+             mul y x\nadd z y"
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn sea_floor(rng: &mut StdRng) -> String {
+    let mut out = String::new();
+    for _ in 0..137 {
+        out.extend((0..139).map(|_| *['>', 'v', '.'].choose(rng).unwrap()));
+        out.push('\n');
+    }
+    out
+}
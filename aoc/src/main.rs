@@ -0,0 +1,93 @@
+//! One binary to run every day of the calendar.
+mod bench;
+mod cli;
+mod days;
+mod generate;
+mod verify;
+
+use std::{path::PathBuf, process::ExitCode};
+
+use cli::{Command, USAGE};
+use common::{Part, Source};
+
+fn main() -> ExitCode {
+    // -v works the same for every command, so it gets taken out up front
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    args.retain(|arg| match common::verbose_flag(arg) {
+        Some(more) => {
+            common::set_verbosity(common::verbosity().saturating_add(more));
+            false
+        }
+        None => true,
+    });
+    let command = match Command::parse(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match command {
+        Command::Run { days, parts, source, json } => run(&days, &parts, source, json),
+        Command::Bench(options) => bench::run(&options),
+        Command::Verify { days } => verify::run(&days),
+    }
+}
+
+fn run(days: &[u8], parts: &[Part], source: Option<Source>, json: bool) -> ExitCode {
+    let mut failed = false;
+    for &day in days {
+        let Some(solved) = days::day(day) else {
+            eprintln!("Day {day}: not solved yet");
+            failed = true;
+            continue;
+        };
+        let source = match source.clone().or_else(|| find_input(day).map(Source::File)) {
+            Some(source) => source,
+            None => {
+                eprintln!("Day {day}: no input.txt found in day_{day}/ or day_{day}/src/");
+                failed = true;
+                continue;
+            }
+        };
+        let input = match source.read(solved.example) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {day}: {e}");
+                failed = true;
+                continue;
+            }
+        };
+        match (solved.solve)(&input, parts) {
+            Ok(run) => {
+                for answer in run.answers {
+                    if json {
+                        println!("{}", answer.to_json(day));
+                    } else {
+                        println!("Day {} Part {}: {}", day, answer.part, answer.answer);
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("Day {day}: bad input in {source}: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+fn find_input(day: u8) -> Option<PathBuf> {
+    find_file(day, "input.txt")
+}
+
+/// The inputs live next to each day's code, either in the crate root or in
+/// `src/`, depending on the day.
+fn find_file(day: u8, name: &str) -> Option<PathBuf> {
+    let dir = PathBuf::from(format!("day_{day}"));
+    [dir.join(name), dir.join("src").join(name)]
+        .into_iter()
+        .find(|path| path.is_file())
+}
@@ -0,0 +1,184 @@
+//! Checks every day's answers against the ones recorded in its
+//! `answers.txt`, so that a change that breaks a day shows up without
+//! having to remember what it used to print.
+//!
+//! Each line of `answers.txt` is `<input> <part> <answer>`, where the input
+//! is a file next to the day's code (or in its `src/`, like input.txt)
+//! and a `\n` in the answer stands for a line break. `input.txt` and
+//! `example.txt` are always checked for both parts, so an answer nobody wrote
+//! down yet shows up as missing; any other input only for the parts listed.
+use std::{fmt, process::ExitCode};
+
+use common::Part;
+
+use crate::{days, find_file};
+
+/// One recorded answer
+#[derive(Debug, Eq, PartialEq)]
+struct Expected {
+    input: String,
+    part: Part,
+    answer: String,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+pub fn run(days: &[u8]) -> ExitCode {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for &day in days {
+        let Some(solved) = days::day(day) else {
+            eprintln!("Day {day}: not solved yet");
+            continue;
+        };
+        let expected = match find_file(day, "answers.txt").map(std::fs::read_to_string) {
+            Some(Ok(answers)) => match parse(&answers) {
+                Ok(expected) => expected,
+                Err(e) => {
+                    eprintln!("Day {day}: bad answers.txt: {e}");
+                    failed += 1;
+                    continue;
+                }
+            },
+            Some(Err(e)) => {
+                eprintln!("Day {day}: could not read answers.txt: {e}");
+                failed += 1;
+                continue;
+            }
+            None => Vec::new(),
+        };
+
+        for input in inputs(day, &expected) {
+            let Some(path) = find_file(day, &input) else {
+                eprintln!("Day {day}: {input} is in answers.txt but does not exist");
+                failed += 1;
+                continue;
+            };
+            let answers = match std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| (solved.solve)(&text, &Part::BOTH))
+            {
+                Ok(run) => run.answers,
+                Err(e) => {
+                    eprintln!("Day {day}: {}: {e}", path.display());
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            let always = input == "input.txt" || input == "example.txt";
+            for answer in answers {
+                let recorded = expected.iter().find(|e| e.input == input && e.part == answer.part);
+                if recorded.is_none() && !always {
+                    continue;
+                }
+                let verdict = check(recorded, &answer.answer);
+                match verdict {
+                    Verdict::Pass => passed += 1,
+                    Verdict::Fail { .. } => failed += 1,
+                    Verdict::Missing => missing += 1,
+                }
+                println!(
+                    "Day {day:>2} {input:<40} part {}: {verdict} ({})",
+                    answer.part,
+                    escape(&answer.answer)
+                );
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+/// Every input of a day worth checking, the usual two first
+fn inputs(day: u8, expected: &[Expected]) -> Vec<String> {
+    let mut inputs: Vec<String> = ["example.txt", "input.txt"]
+        .into_iter()
+        .filter(|name| find_file(day, name).is_some())
+        .map(String::from)
+        .collect();
+    for e in expected {
+        if !inputs.contains(&e.input) {
+            inputs.push(e.input.clone());
+        }
+    }
+    inputs
+}
+
+fn check(expected: Option<&Expected>, answer: &str) -> Verdict {
+    match expected {
+        Some(e) if e.answer == answer => Verdict::Pass,
+        Some(e) => Verdict::Fail { expected: escape(&e.answer) },
+        None => Verdict::Missing,
+    }
+}
+
+fn parse(answers: &str) -> Result<Vec<Expected>, String> {
+    answers
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let mut fields = line.splitn(3, ' ');
+            let (Some(input), Some(part), Some(answer)) = (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!("line {}: expected `<input> <part> <answer>`", i + 1));
+            };
+            Ok(Expected {
+                input: input.to_string(),
+                part: part.parse().map_err(|e| format!("line {}: {e}", i + 1))?,
+                answer: answer.replace("\\n", "\n"),
+            })
+        })
+        .collect()
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\n', "\\n")
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_file() {
+        let expected = parse("example.txt 1 7\n\nexamples/code.txt 2 \\n#.#\\n.#.\n").unwrap();
+        assert_eq!(
+            expected,
+            vec![
+                Expected { input: "example.txt".to_string(), part: Part::One, answer: "7".to_string() },
+                Expected {
+                    input: "examples/code.txt".to_string(),
+                    part: Part::Two,
+                    answer: "\n#.#\n.#.".to_string(),
+                },
+            ]
+        );
+        assert!(parse("example.txt 1").is_err());
+        assert!(parse("example.txt 3 7").is_err());
+    }
+
+    #[test]
+    fn verdicts() {
+        let expected = Expected { input: "input.txt".to_string(), part: Part::One, answer: "42".to_string() };
+        assert_eq!(check(Some(&expected), "42"), Verdict::Pass);
+        assert_eq!(check(Some(&expected), "41"), Verdict::Fail { expected: "42".to_string() });
+        assert_eq!(check(None, "42"), Verdict::Missing);
+    }
+}
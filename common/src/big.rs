@@ -0,0 +1,118 @@
+//! Just enough of an unsigned big integer for the answers that outgrow a
+//! `u128`: adding, multiplying and printing in decimal.
+use std::{fmt::{Display, Formatter}, ops::{Add, Mul}};
+
+/// An unsigned integer of any size, in base 2^32 with the least significant
+/// limb first and no zero limbs at the end
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BigUint(Vec<u32>);
+
+impl BigUint {
+    fn trim(mut self) -> Self {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        self
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let (long, short) = if self.0.len() >= other.0.len() { (self, other) } else { (other, self) };
+        let mut sum = Vec::with_capacity(long.0.len() + 1);
+        let mut carry = 0u64;
+        for (i, &limb) in long.0.iter().enumerate() {
+            let total = limb as u64 + short.0.get(i).copied().unwrap_or(0) as u64 + carry;
+            sum.push(total as u32);
+            carry = total >> 32;
+        }
+        sum.push(carry as u32);
+        BigUint(sum).trim()
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    /// Long multiplication, which is fast enough for numbers of a few
+    /// thousand digits
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::default();
+        }
+        let mut product = vec![0u32; self.0.len() + other.0.len()];
+        for (i, &x) in self.0.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &y) in other.0.iter().enumerate() {
+                let total = product[i + j] as u64 + x as u64 * y as u64 + carry;
+                product[i + j] = total as u32;
+                carry = total >> 32;
+            }
+            product[i + other.0.len()] = carry as u32;
+        }
+        BigUint(product).trim()
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> Self {
+        BigUint::from(n as u128)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(n: u128) -> Self {
+        BigUint(vec![n as u32, (n >> 32) as u32, (n >> 64) as u32, (n >> 96) as u32]).trim()
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        // Back to base 10, nine digits at a time
+        let mut limbs = self.0.clone();
+        let mut chunks = Vec::new();
+        while !limbs.is_empty() {
+            let mut remainder = 0u64;
+            for limb in limbs.iter_mut().rev() {
+                let value = remainder << 32 | *limb as u64;
+                *limb = (value / 1_000_000_000) as u32;
+                remainder = value % 1_000_000_000;
+            }
+            chunks.push(remainder);
+            limbs = BigUint(limbs).trim().0;
+        }
+        write!(f, "{}", chunks.pop().unwrap_or(0))?;
+        for chunk in chunks.iter().rev() {
+            write!(f, "{chunk:09}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(BigUint::default().to_string(), "0");
+        assert!(BigUint::from(0u64).is_zero());
+        assert_eq!(BigUint::from(u64::MAX).to_string(), u64::MAX.to_string());
+        let max = BigUint::from(u128::MAX);
+        assert_eq!((&max + &BigUint::from(1u64)).to_string(), "340282366920938463463374607431768211456");
+        assert_eq!(
+            (&max * &max).to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        assert_eq!((&max * &BigUint::default()).to_string(), "0");
+        // A chunk of nine digits that needs its leading zeros
+        let billion = BigUint::from(1_000_000_000u64);
+        assert_eq!((&billion * &BigUint::from(7u64)).to_string(), "7000000000");
+    }
+}
@@ -0,0 +1,381 @@
+//! The bits every day needs so that the `aoc` runner can drive it.
+pub mod big;
+
+use std::{
+    fmt::{Display, Formatter},
+    io::Read,
+    path::PathBuf,
+    process::ExitCode,
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
+    time::{Duration, Instant},
+};
+
+/// Which half of a day's puzzle to solve.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("there is no part {s}")),
+        }
+    }
+}
+
+/// Where something is in the puzzle input. Both are counted from 1, the way
+/// an editor shows them, so that parse errors can point at the bad token.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// The position of `token`, which has to be a slice of `line`, the
+    /// `index`th line of the input counting from 0 like `enumerate` does.
+    pub fn of(index: usize, line: &str, token: &str) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        debug_assert!(offset <= line.len(), "`{token}` is not part of `{line}`");
+        let offset = offset.min(line.len());
+        Position { line: index + 1, column: line[..offset].chars().count() + 1 }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// The shape every day's solution takes: parse the raw input once, then
+/// answer each part from the parsed input.
+pub trait Solution {
+    /// What the raw puzzle input gets parsed into
+    type Input;
+    /// Why the raw puzzle input could not be parsed
+    type Error: Display;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Which day of the calendar this solves
+    const DAY: u8;
+    /// The example from the puzzle text, if it has been copied in
+    const EXAMPLE: Option<&'static str> = None;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// One part's answer and how long it took to find
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub answer: String,
+    pub time: Duration,
+}
+
+impl Answer {
+    /// One line of `--json` output. The answer is always a string since some
+    /// days answer with a picture rather than a number.
+    pub fn to_json(&self, day: u8) -> String {
+        let mut answer = String::with_capacity(self.answer.len() + 2);
+        for c in self.answer.chars() {
+            match c {
+                '"' => answer.push_str("\\\""),
+                '\\' => answer.push_str("\\\\"),
+                '\n' => answer.push_str("\\n"),
+                c if c.is_control() => answer.push_str(&format!("\\u{:04x}", c as u32)),
+                c => answer.push(c),
+            }
+        }
+        format!(
+            r#"{{"day":{day},"part":{},"answer":"{answer}","duration_ns":{}}}"#,
+            self.part,
+            self.time.as_nanos()
+        )
+    }
+}
+
+/// Everything a [`Solver`] found out about an input
+#[derive(Clone, Debug)]
+pub struct Run {
+    /// How long parsing the input took
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// A [`Solution`] with its types erased so the runner can keep every day in
+/// one table. It parses the input and answers the requested parts in order,
+/// timing each stage as it goes.
+pub type Solver = fn(&str, &[Part]) -> Result<Run, String>;
+
+/// Everything the runner needs to know about a day
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub solve: Solver,
+    pub example: Option<&'static str>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Day { solve: solver::<S>, example: S::EXAMPLE }
+    }
+}
+
+pub fn solver<S: Solution>(input: &str, parts: &[Part]) -> Result<Run, String> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|e| e.to_string())?;
+    let parse = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&parsed).to_string(),
+                Part::Two => S::part2(&parsed).to_string(),
+            };
+            Answer { part, answer, time: start.elapsed() }
+        })
+        .collect();
+    Ok(Run { parse, answers })
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// How much diagnostic output [`debug!`] lets through, 0 being none
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+/// How much more verbose a command line flag asks for: `-v` or `--verbose`
+/// is one level, `-vv` two and so on, topping out at `u8::MAX`. `None` if
+/// it is some other flag.
+pub fn verbose_flag(arg: &str) -> Option<u8> {
+    match arg {
+        "--verbose" => Some(1),
+        _ if arg.len() > 1 && arg.starts_with('-') && arg[1..].bytes().all(|b| b == b'v') => {
+            Some((arg.len() - 1).min(u8::MAX as usize) as u8)
+        }
+        _ => None,
+    }
+}
+
+/// Prints diagnostics to stderr, but only once the verbosity is at least
+/// `level`, so they stay out of the answers (and out of `--json`) unless
+/// somebody asks for them. Level 1 is for progress, 2 for the really chatty
+/// stuff.
+#[macro_export]
+macro_rules! debug {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::verbosity() >= $level {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Where to read a day's puzzle input from
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    /// The day's [`Solution::EXAMPLE`]
+    Example,
+}
+
+impl Source {
+    /// A path on the command line, where `-` means stdin like usual
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    /// The source among a day's own arguments, `input.txt` unless one of
+    /// them says otherwise. `option` gets first go at every argument, taking
+    /// any value it needs from `args`, and says whether it knew it.
+    pub fn from_args<'a, I>(args: &mut I, mut option: impl FnMut(&'a str, &mut I) -> Result<bool, String>) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a String>,
+    {
+        let mut source = None;
+        while let Some(arg) = args.next() {
+            if option(arg, args)? {
+                continue;
+            }
+            match arg.as_str() {
+                "--example" | "-e" if source.is_none() => source = Some(Source::Example),
+                path if source.is_none() && (path == "-" || !path.starts_with('-')) => {
+                    source = Some(Source::from_arg(path));
+                }
+                _ => return Err(format!("unexpected argument `{arg}`")),
+            }
+        }
+        Ok(source.unwrap_or(Source::File(PathBuf::from("input.txt"))))
+    }
+
+    /// Reads the whole input. `example` is the day's embedded example.
+    pub fn read(&self, example: Option<&str>) -> Result<String, String> {
+        match self {
+            Source::File(path) => std::fs::read_to_string(path)
+                .map_err(|e| format!("could not read {}: {e}", path.display())),
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("could not read stdin: {e}"))?;
+                Ok(input)
+            }
+            Source::Example => example
+                .map(str::to_string)
+                .ok_or("there is no example for this day yet".to_string()),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Example => write!(f, "the example"),
+        }
+    }
+}
+
+const DAY_USAGE: &str = "\
+Usage: day_N [PATH | - | --example] [--json] [-v...]
+
+Reads the puzzle input from PATH, from stdin for `-`, or uses the example
+from the puzzle text. Without any of them it reads input.txt.
+
+--json prints each answer as a {day, part, answer, duration_ns} object on a
+line of its own, and -v (or -vv for even more) prints what the solution is
+up to on stderr.";
+
+/// The `main` of every day's own binary: read the input (`input.txt` in the
+/// current directory unless told otherwise) and print both parts. Bad input
+/// gets reported rather than panicked over.
+pub fn main<S: Solution>() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (mut json, mut help) = (false, false);
+    let source = Source::from_args(&mut args.iter(), |arg, _| {
+        match arg {
+            "--help" | "-h" => help = true,
+            "--json" => json = true,
+            flag if verbose_flag(flag).is_some() => {
+                set_verbosity(verbosity().saturating_add(verbose_flag(flag).unwrap()));
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    });
+    let source = match source {
+        _ if help => {
+            println!("{DAY_USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{DAY_USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let input = match source.read(S::EXAMPLE) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let run = match solver::<S>(&input, &Part::BOTH) {
+        Ok(run) => run,
+        Err(e) => {
+            eprintln!("error: bad input in {source}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for answer in run.answers {
+        if json {
+            println!("{}", answer.to_json(S::DAY));
+        } else {
+            println!("Part {}: {}", answer.part, answer.answer);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_answers() {
+        let answer = Answer { part: Part::Two, answer: "#.\n\"#\"".to_string(), time: Duration::from_nanos(42) };
+        assert_eq!(
+            answer.to_json(13),
+            r##"{"day":13,"part":2,"answer":"#.\n\"#\"","duration_ns":42}"##
+        );
+    }
+
+    #[test]
+    fn sources() {
+        let args = |line: &str| line.split_whitespace().map(str::to_string).collect::<Vec<_>>();
+        let mut window = None;
+        let mut source = |line| {
+            Source::from_args(&mut args(line).iter(), |arg, args| match arg {
+                "--window" => {
+                    window = args.next().cloned();
+                    Ok(true)
+                }
+                _ => Ok(false),
+            })
+        };
+        assert_eq!(source(""), Ok(Source::File(PathBuf::from("input.txt"))));
+        assert_eq!(source("--window 3"), Ok(Source::File(PathBuf::from("input.txt"))));
+        assert_eq!(source("-"), Ok(Source::Stdin));
+        assert_eq!(source("--window 5 -e"), Ok(Source::Example));
+        assert_eq!(source("in.txt --window 4"), Ok(Source::File(PathBuf::from("in.txt"))));
+        assert_eq!(source("in.txt -e"), Err("unexpected argument `-e`".to_string()));
+        assert_eq!(source("--json"), Err("unexpected argument `--json`".to_string()));
+        assert_eq!(window.as_deref(), Some("4"));
+    }
+
+    #[test]
+    fn verbose_flags() {
+        assert_eq!(verbose_flag("-v"), Some(1));
+        assert_eq!(verbose_flag("-vvv"), Some(3));
+        assert_eq!(verbose_flag("--verbose"), Some(1));
+        assert_eq!(verbose_flag(&format!("-{}", "v".repeat(300))), Some(u8::MAX));
+        assert_eq!(verbose_flag("-"), None);
+        assert_eq!(verbose_flag("-e"), None);
+    }
+}
@@ -0,0 +1,168 @@
+pub mod report;
+
+use std::{collections::VecDeque, fmt::{Display, Formatter}};
+use common::{Position, Solution};
+
+/// What can go wrong reading the sonar sweep
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Every line should be a single depth
+    NotADepth { at: Position, found: String },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotADepth { at, found } => {
+                write!(f, "{at}: expected a depth, found `{found}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        let depths: Vec<u32> = input
+            .trim_end()
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let depth = line.trim();
+                depth.parse().map_err(|_| ParseError::NotADepth {
+                    at: Position::of(i, line, depth),
+                    found: depth.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(depths)
+    }
+
+    // Too few depths for even one window means there's nothing to compare,
+    // so nothing increases
+    fn part1(depths: &Vec<u32>) -> usize {
+        count_increases(depths.iter().copied(), 1).unwrap_or(0)
+    }
+
+    fn part2(depths: &Vec<u32>) -> usize {
+        count_increases(depths.iter().copied(), 3).unwrap_or(0)
+    }
+}
+
+/// Why a window can't be slid over the depths
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WindowError {
+    /// A window has to hold at least one depth
+    Empty,
+    /// Fewer depths than fit in a single window
+    TooShort { window: usize, depths: usize },
+}
+
+impl Display for WindowError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            WindowError::Empty => write!(f, "a window needs at least one depth"),
+            WindowError::TooShort { window, depths } => {
+                write!(f, "a window of {window} needs at least {window} depths, but there are only {depths}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WindowError {}
+
+/// The sum of every window of `k` depths in a row, worked out as the
+/// depths stream past: each step adds the depth coming in and takes away
+/// the one going out, so only the window itself is ever kept around.
+pub struct WindowSums<I> {
+    depths: I,
+    window: VecDeque<u32>,
+    /// The first window's sum hasn't been handed out yet
+    first: bool,
+    sum: u64,
+}
+
+impl<I: Iterator<Item = u32>> WindowSums<I> {
+    pub fn new(depths: impl IntoIterator<IntoIter = I>, k: usize) -> Result<Self, WindowError> {
+        if k == 0 {
+            return Err(WindowError::Empty);
+        }
+        let mut depths = depths.into_iter();
+        let window: VecDeque<u32> = depths.by_ref().take(k).collect();
+        if window.len() < k {
+            return Err(WindowError::TooShort { window: k, depths: window.len() });
+        }
+        let sum = window.iter().map(|&d| d as u64).sum();
+        Ok(WindowSums { depths, window, first: true, sum })
+    }
+}
+
+impl<I: Iterator<Item = u32>> Iterator for WindowSums<I> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.first {
+            self.first = false;
+            return Some(self.sum);
+        }
+        let next = self.depths.next()?;
+        let gone = self.window.pop_front().unwrap();
+        self.window.push_back(next);
+        self.sum = self.sum + next as u64 - gone as u64;
+        Some(self.sum)
+    }
+}
+
+/// How many windows of `k` depths have a bigger sum than the one before.
+/// A window of 1 is just comparing each depth with the last.
+pub fn count_increases(depths: impl IntoIterator<Item = u32>, k: usize) -> Result<usize, WindowError> {
+    let mut sums = WindowSums::new(depths, k)?;
+    let mut last = sums.next().unwrap();
+    let mut count = 0;
+    for sum in sums {
+        if sum > last {
+            count += 1;
+        }
+        last = sum;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows() {
+        let depths = Day1::parse(Day1::EXAMPLE.unwrap()).unwrap();
+        assert_eq!(count_increases(depths.iter().copied(), 1), Ok(7));
+        assert_eq!(count_increases(depths.iter().copied(), 3), Ok(5));
+        assert_eq!(count_increases(depths.iter().copied(), 10), Ok(0));
+        assert_eq!(
+            WindowSums::new(depths.iter().copied(), 3).unwrap().take(3).collect::<Vec<_>>(),
+            vec![607, 618, 618]
+        );
+        // Any iterator of depths will do, not just a slice
+        assert_eq!(count_increases((1..=1000).step_by(7), 4), Ok(139));
+    }
+
+    #[test]
+    fn short_inputs() {
+        assert_eq!(count_increases([], 1), Err(WindowError::TooShort { window: 1, depths: 0 }));
+        assert_eq!(count_increases([1, 2], 3), Err(WindowError::TooShort { window: 3, depths: 2 }));
+        assert_eq!(count_increases([1, 2], 0), Err(WindowError::Empty));
+        let two = Day1::parse("199\n200").unwrap();
+        assert_eq!((Day1::part1(&two), Day1::part2(&two)), (1, 0));
+        assert_eq!(Day1::part1(&Day1::parse("").unwrap()), 0);
+    }
+}
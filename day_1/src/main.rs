@@ -1,60 +1,62 @@
-use std::fs;
+use std::process::ExitCode;
 
-fn main() {
-    let input = read_input("input.txt");
-    let split = input.trim().split('\n');
-    let depths = split
-        .into_iter()
-        .map(|s| -> u32 { s.parse().unwrap() })
-        .collect();
-    let solution1 = solve1(&depths);
-    println!("Part 1: {}", solution1);
-    let solution2 = solve2(depths);
-    println!("Part 2: {}", solution2);
-}
+use common::{Solution, Source};
+use day_1::{report::Report, Day1};
 
-fn read_input(filename: &str) -> String {
-    let result = fs::read_to_string(filename).expect("Something went wrong reading the file");
-    result
-}
+const REPORT_USAGE: &str = "\
+Usage: day_1 --report [PATH | - | --example] [--json] [--window K] [--bucket N]
 
-fn solve1(depths: &Vec<u32>) -> String {
-    let mut v = depths.iter();
-    let mut d = v.next().unwrap();
-    let mut count = 0;
-    loop {
-        let n = v.next();
-        match n {
-            None => break,
-            Some(_) => {
-                let m = n.unwrap();
-                if m > d {
-                    count += 1;
-                }
-                d = m
-            }
-        }
+Prints the longest climb and descent, the biggest drop, and a histogram of
+how much the sum of K depths (3 unless told otherwise) changes from one
+window to the next, in buckets N wide (10 by default).";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.split_first() {
+        Some((flag, rest)) if flag == "--report" => print_report(rest),
+        _ => common::main::<Day1>(),
     }
-    count.to_string()
 }
 
-fn solve2(depths: Vec<u32>) -> String {
-    let mut d = sum(&depths[0..3]);
-    let mut count = 0;
-    for i in 1..(depths.len() - 2) {
-        let n = sum(&depths[i..(i + 3)]);
-        if n > d {
-            count += 1;
+/// `day_1 --report` describes the depths instead of solving the puzzle
+fn print_report(args: &[String]) -> ExitCode {
+    match report(args) {
+        Ok(report) => {
+            print!("{report}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
         }
-        d = n
     }
-    count.to_string()
 }
 
-fn sum(arr: &[u32]) -> u32 {
-    let mut s = 0;
-    for n in arr {
-        s += n;
+fn report(args: &[String]) -> Result<String, String> {
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        return Ok(format!("{REPORT_USAGE}\n"));
     }
-    s
+    let (mut json, mut window, mut bucket) = (false, 3, 10u32);
+    let number = |args: &mut std::slice::Iter<String>, name| -> Result<_, String> {
+        let value = args.next().ok_or(format!("{name} needs a number"))?;
+        match value.parse() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(format!("{name} needs a number above 0, found `{value}`")),
+        }
+    };
+    let source = Source::from_args(&mut args.iter(), |arg, args| {
+        match arg {
+            "--json" => json = true,
+            "--window" => window = number(args, "--window")? as usize,
+            "--bucket" => bucket = number(args, "--bucket")?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })
+    .map_err(|e| format!("{e}\n\n{REPORT_USAGE}"))?;
+
+    let input = source.read(Day1::EXAMPLE)?;
+    let depths = Day1::parse(&input).map_err(|e| format!("bad input in {source}: {e}"))?;
+    let report = Report::new(&depths, window, bucket).map_err(|e| e.to_string())?;
+    Ok(if json { report.to_json() + "\n" } else { report.to_string() })
 }
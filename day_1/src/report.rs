@@ -0,0 +1,192 @@
+//! A closer look at the depths than the two counts give: the longest climbs
+//! and descents, the steepest drop, and how much the window sums tend to
+//! move from one window to the next.
+use std::{collections::BTreeMap, fmt::{Display, Formatter}};
+
+use crate::{WindowError, WindowSums};
+
+/// A stretch of depths that only ever goes one way
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Run {
+    /// Index of the first depth of the run
+    pub start: usize,
+    /// How many depths are in it, so a single depth is a run of 1
+    pub length: usize,
+}
+
+/// The biggest fall from one depth to the very next
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DepthDrop {
+    /// Index of the depth that was dropped to
+    pub index: usize,
+    pub from: u32,
+    pub to: u32,
+}
+
+impl DepthDrop {
+    pub fn size(&self) -> u32 {
+        self.from - self.to
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    pub depths: usize,
+    pub window: usize,
+    pub increasing: Run,
+    pub decreasing: Run,
+    /// `None` if the depths never go down
+    pub biggest_drop: Option<DepthDrop>,
+    /// How wide each bucket of the histogram is
+    pub bucket: u32,
+    /// How many times the window sum changed by an amount in each bucket,
+    /// keyed by the smallest change the bucket holds
+    pub histogram: BTreeMap<i64, usize>,
+}
+
+impl Report {
+    /// The report on `depths`, with the histogram over sums of `window`
+    /// depths in buckets `bucket` wide. The earliest run wins a tie.
+    pub fn new(depths: &[u32], window: usize, bucket: u32) -> Result<Self, WindowError> {
+        assert!(bucket > 0, "histogram buckets have to be at least 1 wide");
+        let mut histogram = BTreeMap::new();
+        let mut sums = WindowSums::new(depths.iter().copied(), window)?;
+        let mut last = sums.next().unwrap() as i64;
+        for sum in sums {
+            let delta = sum as i64 - last;
+            *histogram.entry(delta.div_euclid(bucket as i64) * bucket as i64).or_insert(0) += 1;
+            last = sum as i64;
+        }
+
+        let biggest_drop = depths
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[1] < pair[0])
+            .map(|(i, pair)| DepthDrop { index: i + 1, from: pair[0], to: pair[1] })
+            .fold(None, |biggest: Option<DepthDrop>, drop| match biggest {
+                Some(b) if b.size() >= drop.size() => Some(b),
+                _ => Some(drop),
+            });
+
+        Ok(Report {
+            depths: depths.len(),
+            window,
+            increasing: longest_run(depths, |a, b| a < b),
+            decreasing: longest_run(depths, |a, b| a > b),
+            biggest_drop,
+            bucket,
+            histogram,
+        })
+    }
+
+    /// The whole report as a single JSON object
+    pub fn to_json(&self) -> String {
+        let run = |run: Run| format!(r#"{{"start":{},"length":{}}}"#, run.start, run.length);
+        let drop = match self.biggest_drop {
+            Some(d) => format!(r#"{{"index":{},"from":{},"to":{},"size":{}}}"#, d.index, d.from, d.to, d.size()),
+            None => "null".to_string(),
+        };
+        let histogram: Vec<String> = self
+            .histogram
+            .iter()
+            .map(|(from, count)| {
+                format!(r#"{{"from":{from},"to":{},"count":{count}}}"#, from + self.bucket as i64 - 1)
+            })
+            .collect();
+        format!(
+            r#"{{"depths":{},"window":{},"increasing":{},"decreasing":{},"biggest_drop":{drop},"bucket":{},"histogram":[{}]}}"#,
+            self.depths,
+            self.window,
+            run(self.increasing),
+            run(self.decreasing),
+            self.bucket,
+            histogram.join(",")
+        )
+    }
+}
+
+/// The longest run where every depth is `further` along than the one before
+fn longest_run(depths: &[u32], further: impl Fn(u32, u32) -> bool) -> Run {
+    if depths.is_empty() {
+        return Run::default();
+    }
+    let mut longest = Run { start: 0, length: 1 };
+    let mut current = longest;
+    for (i, pair) in depths.windows(2).enumerate() {
+        if further(pair[0], pair[1]) {
+            current.length += 1;
+        } else {
+            current = Run { start: i + 1, length: 1 };
+        }
+        if current.length > longest.length {
+            longest = current;
+        }
+    }
+    longest
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(f, "{} depths", self.depths)?;
+        let Run { start, length } = self.increasing;
+        writeln!(f, "Longest climb: {length} depths from index {start}")?;
+        let Run { start, length } = self.decreasing;
+        writeln!(f, "Longest descent: {length} depths from index {start}")?;
+        match self.biggest_drop {
+            Some(d) => writeln!(f, "Biggest drop: {} ({} to {}) at index {}", d.size(), d.from, d.to, d.index)?,
+            None => writeln!(f, "Biggest drop: none, it never gets shallower")?,
+        }
+
+        writeln!(f, "Change in the sum of {} depths:", self.window)?;
+        let most = self.histogram.values().copied().max().unwrap_or(0);
+        let labels: Vec<String> = self
+            .histogram
+            .keys()
+            .map(|from| match self.bucket {
+                1 => format!("{from}"),
+                _ => format!("{from}..={}", from + self.bucket as i64 - 1),
+            })
+            .collect();
+        let width = labels.iter().map(String::len).max().unwrap_or(0);
+        for (label, count) in labels.iter().zip(self.histogram.values()) {
+            // Scaled so that the tallest bar is 50 wide, but never so far that
+            // a bucket with anything in it looks empty
+            let bar = (count * 50).div_ceil(most);
+            writeln!(f, "  {label:>width$} | {:<50} {count}", "#".repeat(bar))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day1;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let depths = Day1::parse(Day1::EXAMPLE.unwrap()).unwrap();
+        let report = Report::new(&depths, 3, 1).unwrap();
+        // Both climbs are 4 long, so the first one is the one reported
+        assert_eq!(report.increasing, Run { start: 0, length: 4 });
+        assert_eq!(report.decreasing, Run { start: 3, length: 2 });
+        assert_eq!(report.biggest_drop, Some(DepthDrop { index: 4, from: 210, to: 200 }));
+        // 607 618 618 617 647 716 769 792
+        assert_eq!(report.histogram, BTreeMap::from([(-1, 1), (0, 1), (11, 1), (23, 1), (30, 1), (53, 1), (69, 1)]));
+
+        let report = Report::new(&depths, 3, 10).unwrap();
+        assert_eq!(report.histogram, BTreeMap::from([(-10, 1), (0, 1), (10, 1), (20, 1), (30, 1), (50, 1), (60, 1)]));
+        assert!(report.to_json().contains(r#""histogram":[{"from":-10,"to":-1,"count":1},{"from":0,"to":9,"count":1}"#));
+    }
+
+    #[test]
+    fn flat_and_short() {
+        let report = Report::new(&[5, 5, 5], 1, 10).unwrap();
+        assert_eq!(report.increasing, Run { start: 0, length: 1 });
+        assert_eq!(report.biggest_drop, None);
+        assert_eq!(report.histogram, BTreeMap::from([(0, 2)]));
+        assert!(report.to_json().contains(r#""biggest_drop":null"#));
+        assert_eq!(Report::new(&[5, 5], 3, 10), Err(WindowError::TooShort { window: 3, depths: 2 }));
+    }
+}
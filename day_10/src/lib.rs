@@ -0,0 +1,157 @@
+use std::{collections::HashMap, fmt::{Display, Formatter}};
+use common::{Position, Solution};
+
+#[macro_use]
+extern crate lazy_static;
+
+lazy_static! {
+    static ref BRACKETS: HashMap<char, char> = HashMap::from([
+        ('(', ')'),
+        ('[', ']'),
+        ('{', '}'),
+        ('<', '>'),
+    ]);
+    
+    static ref POINTS: HashMap<char, usize> = HashMap::from([
+        (')', 3),
+        (']', 57),
+        ('}', 1197),
+        ('>', 25137),
+    ]);
+
+    static ref POINTS2: HashMap<char, usize> = HashMap::from([
+        (')', 1),
+        (']', 2),
+        ('}', 3),
+        ('>', 4),
+    ]);
+
+}
+
+/// What can go wrong reading the navigation subsystem
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Lines are made of the brackets `()[]{}<>` and nothing else
+    NotABracket { at: Position, found: char },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotABracket { at, found } => {
+                write!(f, "{at}: expected one of `()[]{{}}<>`, found `{found}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 10;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(raw: &str) -> Result<Vec<String>, ParseError> {
+        let mut lines = Vec::new();
+        for (i, line) in raw.trim_end().lines().enumerate() {
+            if let Some((j, c)) = line.char_indices().find(|&(_, c)| !"()[]{}<>".contains(c)) {
+                return Err(ParseError::NotABracket { at: Position::of(i, line, &line[j..]), found: c });
+            }
+            lines.push(line.to_string());
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Vec<String>) -> usize {
+        part1(lines)
+    }
+
+    fn part2(lines: &Vec<String>) -> usize {
+        part2(lines)
+    }
+}
+
+fn part1(lines: &[String]) -> usize {
+    let mut pts = 0;
+    for line in lines {
+        let mut stack: Vec<char> = Vec::with_capacity(100);
+        for c in line.chars() {
+            if BRACKETS.contains_key(&c) {
+                stack.push(c);
+            } else if stack.last().map(|open| BRACKETS[open]) == Some(c) {
+                stack.pop();
+            } else {
+                pts += POINTS.get(&c).unwrap();
+                break;
+            }
+        }
+    }
+    pts
+}
+
+fn is_corrupted(line: &str) -> bool {
+   let mut stack: Vec<char> = Vec::with_capacity(100);
+   for c in line.chars() {
+       if BRACKETS.contains_key(&c) {
+           stack.push(c);
+       } else if stack.last().map(|open| BRACKETS[open]) == Some(c) {
+           stack.pop();
+       } else {
+           return true;
+       }
+   }
+   false
+}
+
+fn complete_line(line: &str) -> usize {
+   let mut stack: Vec<char> = Vec::with_capacity(100);
+   let mut pts: usize = 0;
+   for c in line.chars() {
+       if BRACKETS.contains_key(&c) {
+           stack.push(c);
+       } else {
+           stack.pop();
+       }
+   }
+   while let Some(c) = stack.pop() {
+        pts = pts * 5 + POINTS2.get(BRACKETS.get(&c).unwrap()).unwrap();
+   }
+   pts
+}
+
+fn part2(lines: &[String]) -> usize {
+    let mut scores: Vec<usize> = Vec::new();
+    for line in lines {
+        if !is_corrupted(line) {
+            scores.push(complete_line(line));
+        }
+    }
+    scores.sort();
+    // The puzzle promises an odd number of incomplete lines, but there
+    // might be none at all
+    scores.get(scores.len() / 2).copied().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_lines() {
+        assert_eq!(
+            Day10::parse("(())\n([x])"),
+            Err(ParseError::NotABracket { at: Position { line: 2, column: 3 }, found: 'x' })
+        );
+        // Closing a chunk that was never opened is as corrupt as closing
+        // the wrong one
+        let lines = Day10::parse(")\n([])>").unwrap();
+        assert_eq!(Day10::part1(&lines), 3 + 25137);
+        assert_eq!(Day10::part2(&lines), 0);
+    }
+}
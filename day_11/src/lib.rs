@@ -0,0 +1,125 @@
+use common::Solution;
+use grid::Grid;
+
+pub type Octos = Grid<u8>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Octos;
+    type Error = grid::ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 11;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(raw: &str) -> Result<Octos, grid::ParseError> {
+        raw.parse()
+    }
+
+    fn part1(octos: &Octos) -> usize {
+        part1(&mut octos.clone())
+    }
+
+    fn part2(octos: &Octos) -> usize {
+        part2(&mut octos.clone())
+    }
+}
+
+fn increment_all(octos: &mut Octos) {
+    for octo in octos.iter_mut() {
+        *octo += 1;
+    }
+}
+
+fn flash(pos: (usize, usize), octos: &mut Octos, n_flashes: &mut usize) {
+    if octos[pos] > 0 {
+        octos[pos] += 1;
+    }
+    if octos[pos] > 9 {
+        *n_flashes += 1;
+        octos[pos] = 0;
+        for neighbor in octos.neighbors8(pos) {
+            flash(neighbor, octos, n_flashes);
+        }
+    } 
+}
+
+fn step(octos: &mut Octos, n_flashes: &mut usize) {
+    increment_all(octos);
+    // Flashes
+    for pos in octos.positions() {
+        if octos[pos] > 9 {
+            flash(pos, octos, n_flashes);
+        }
+    }
+}
+
+fn part1(octos: &mut Octos) -> usize {
+
+    let mut n_flashes: usize = 0;
+
+    for _step_n in 0..100 {
+        //println!("Step {}\n--------", _step_n);
+        //println!("{}\n", octos);
+        step(octos, &mut n_flashes);
+    }
+
+    n_flashes
+}
+
+fn part2(octos: &mut Octos) -> usize {
+    let mut n_flashes = 0;
+    let mut step_counter = 0;
+    let max_flashes = octos.width() * octos.height();
+
+    loop {
+        step(octos, &mut n_flashes);
+        step_counter += 1;
+        if n_flashes == max_flashes {
+            break;
+        }
+        n_flashes = 0;
+    }
+    step_counter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_part() {
+        let rows = ["5483143223",
+                    "2745854711", 
+                    "5264556173",
+                    "6141336146",
+                    "6357385478",
+                    "4167524645",
+                    "2176841721",
+                    "6882881134",
+                    "4846848554",
+                    "5283751526",];
+        let mut dumbos: Octos = rows.join("\n").parse().unwrap();
+        let ans = part1(&mut dumbos);
+        //println!("{}", ans);
+        assert_eq!(ans, 1656);
+    }
+
+    #[test]
+    fn second_part() {
+        let rows = ["5483143223",
+                    "2745854711", 
+                    "5264556173",
+                    "6141336146",
+                    "6357385478",
+                    "4167524645",
+                    "2176841721",
+                    "6882881134",
+                    "4846848554",
+                    "5283751526",];
+        let mut dumbos: Octos = rows.join("\n").parse().unwrap();
+        
+        assert_eq!(part2(&mut dumbos), 195);
+    }
+}
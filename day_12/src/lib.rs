@@ -0,0 +1,180 @@
+use std::{collections::{HashMap, HashSet}, fmt::{Display, Formatter}};
+use regex::Regex;
+use common::{Position, Solution};
+
+#[macro_use]
+extern crate lazy_static;
+
+pub type Caves = HashMap<String, Vec<String>>;
+
+/// What can go wrong reading the map of the caves
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Paths look like `start-A`
+    NotAPath { at: Position, found: String },
+    /// Caves are named in all lowercase letters if they're small or all
+    /// uppercase ones if they're big
+    NotACave { at: Position, found: String },
+    /// There's no `start` cave to set off from
+    NoStart,
+    /// A path between two big caves, which could be walked back and forth
+    /// forever
+    BigToBig { at: Position },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotAPath { at, found } => write!(f, "{at}: expected a path like `start-A`, found `{found}`"),
+            ParseError::NotACave { at, found } => write!(f, "{at}: expected a small or big cave, found `{found}`"),
+            ParseError::NoStart => write!(f, "expected a path from `start`, found none"),
+            ParseError::BigToBig { at } => {
+                write!(f, "{at}: expected a small cave at one end, found two big ones with endless paths")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Caves;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 12;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(raw: &str) -> Result<Caves, ParseError> {
+        let mut map: Caves = HashMap::new();
+
+        for (i, line) in raw.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+            let t = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::NotAPath { at: Position::of(i, line, line), found: line.to_string() })?;
+            for cave in [t.0, t.1] {
+                if !is_small(cave) && !is_big(cave) {
+                    return Err(ParseError::NotACave { at: Position::of(i, line, cave), found: cave.to_string() });
+                }
+            }
+            if is_big(t.0) && is_big(t.1) {
+                return Err(ParseError::BigToBig { at: Position::of(i, line, line) });
+            }
+            add_to_map(t, &mut map);
+        }
+        if !map.contains_key("start") {
+            return Err(ParseError::NoStart);
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Caves) -> usize {
+        part1(map)
+    }
+
+    fn part2(map: &Caves) -> usize {
+        part2(map)
+    }
+}
+
+fn part1(map: &Caves) -> usize {
+    let mut n_paths = 0usize;
+    find_paths(vec!["start"], map, &mut n_paths);
+    n_paths
+}
+
+fn part2(map: &Caves) -> usize {
+    let mut n_paths = 0usize;
+    find_paths2(vec!["start"], map, &mut n_paths);
+    n_paths
+}
+
+fn find_paths<'a>(path: Vec<&'a str>, map: &'a Caves, count: &mut usize) {
+    for dest in map.get(path[path.len()-1]).unwrap() {
+        let dest = dest.as_str();
+        if dest == "end" {
+            *count += 1;
+            continue;
+        }
+        if is_small(dest) && path.contains(&dest) {
+            continue;
+        }
+        let mut new_path = path.clone();
+        new_path.push(dest);
+        find_paths(new_path, map, count);
+    }
+}
+
+fn find_paths2<'a>(path: Vec<&'a str>, map: &'a Caves, count: &mut usize) {
+    for dest in map.get(path[path.len()-1]).unwrap() {
+        let dest = dest.as_str();
+        if dest == "end" {
+            *count += 1;
+            continue;
+        }
+        if dest == "start" {
+            continue;
+        }
+        if has_two_small(&path) && is_small(dest) && path.contains(&dest) {
+            continue;
+        }
+        let mut new_path = path.clone();
+        new_path.push(dest);
+        find_paths2(new_path, map, count);
+    }
+}
+
+fn add_to_map((a, b): (&str, &str), map: &mut Caves) {
+    map.entry(a.to_string()).or_default().push(b.to_string());
+    map.entry(b.to_string()).or_default().push(a.to_string());
+}
+
+fn is_small(cave: &str) -> bool {
+    lazy_static! {
+        static ref SMALL: Regex = Regex::new(r"^[a-z]+$").unwrap();
+    }
+    SMALL.is_match(cave)
+}
+
+fn is_big(cave: &str) -> bool {
+    lazy_static! {
+        static ref BIG: Regex = Regex::new(r"^[A-Z]+$").unwrap();
+    }
+    BIG.is_match(cave)
+}
+
+fn has_two_small(path: &[&str]) -> bool {
+    let mut smalls: HashSet<&str> = HashSet::new();
+    for &cave in path {
+        if smalls.contains(&cave) {
+            return true;
+        } else if is_small(cave) {
+            smalls.insert(cave);
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_maps() {
+        let at = |line, column| Position { line, column };
+        assert_eq!(
+            Day12::parse("start-A\nA end").err(),
+            Some(ParseError::NotAPath { at: at(2, 1), found: "A end".to_string() })
+        );
+        assert_eq!(
+            Day12::parse("start-A\nA-Bc").err(),
+            Some(ParseError::NotACave { at: at(2, 3), found: "Bc".to_string() })
+        );
+        assert_eq!(Day12::parse("start-A\nA-B").err(), Some(ParseError::BigToBig { at: at(2, 1) }));
+        assert_eq!(Day12::parse("a-end").err(), Some(ParseError::NoStart));
+        assert_eq!(Day12::part1(&Day12::parse("start-A\nA-b").unwrap()), 0);
+    }
+}
@@ -0,0 +1,205 @@
+use regex::Regex;
+use std::{cmp::{max, min}, fmt::{Display, Formatter}};
+use common::{Position, Solution};
+use grid::Grid;
+
+type Paper = Grid<bool>;
+
+pub struct Manual {
+    paper: Paper,
+    folds: Vec<(bool, usize)>,
+}
+
+/// The code that shows up once the paper is completely folded
+pub struct Code(Paper);
+
+// Starts on a new line so that the letters line up
+impl Display for Code {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(f)?;
+        write!(f, "{}", self.0)
+    }
+}
+
+/// What can go wrong reading the manual
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// A blank line comes between the dots and the folds
+    NoBlankLine,
+    /// Dots look like `6,10`
+    NotADot { at: Position, found: String },
+    /// A coordinate too big to be on any paper
+    NotACoordinate { at: Position, found: String },
+    /// Folds look like `fold along y=7`
+    NotAFold { at: Position, found: String },
+    /// A fold along a line that isn't on the paper any more, which is
+    /// `size` dots across or down by then
+    FoldOutside { at: Position, size: usize },
+    /// There has to be a fold for part 1 to make
+    NoFolds,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NoBlankLine => write!(f, "expected a blank line between the dots and the folds, found none"),
+            ParseError::NotADot { at, found } => write!(f, "{at}: expected a dot like `6,10`, found `{found}`"),
+            ParseError::NotACoordinate { at, found } => write!(f, "{at}: expected a coordinate, found `{found}`"),
+            ParseError::NotAFold { at, found } => {
+                write!(f, "{at}: expected a fold like `fold along y=7`, found `{found}`")
+            }
+            ParseError::FoldOutside { at, size } => {
+                write!(f, "{at}: expected a fold inside paper {size} dots across, found one outside it")
+            }
+            ParseError::NoFolds => write!(f, "expected some folds, found none"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Manual;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = Code;
+    const DAY: u8 = 13;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(raw: &str) -> Result<Manual, ParseError> {
+        let lines: Vec<&str> = raw.lines().collect();
+        let empty_line: usize = lines
+            .iter()
+            .position(|&x| x.is_empty())
+            .ok_or(ParseError::NoBlankLine)?;
+
+        let mut pts: Vec<(usize, usize)> = Vec::new();
+        let pat = Regex::new(r"^(\d+),(\d+)$").unwrap();
+        for (i, &line) in lines[..empty_line].iter().enumerate() {
+            let cap = pat
+                .captures(line)
+                .ok_or_else(|| ParseError::NotADot { at: Position::of(i, line, line), found: line.to_string() })?;
+            let coordinate = |n: usize| {
+                let m = cap.get(n).unwrap();
+                m.as_str().parse().map_err(|_| ParseError::NotACoordinate {
+                    at: Position::of(i, line, &line[m.start()..]),
+                    found: m.as_str().to_string(),
+                })
+            };
+            pts.push((coordinate(1)?, coordinate(2)?));
+        }
+
+        let (x_max, y_max) = pts.iter()
+            .fold((0,0), |acc, (x,y)| (max(acc.0,*x), max(acc.1,*y)));
+
+        let mut paper = Grid::new(x_max+1, y_max+1, false);
+        for (x, y) in pts.into_iter() {
+            paper[(y, x)] = true;
+        }
+
+        // How big the paper is as it gets folded, so that every fold is on it
+        let (mut width, mut height) = (paper.width(), paper.height());
+        let mut folds: Vec<_> = Vec::new();
+        for (i, &line) in lines.iter().enumerate().skip(empty_line) {
+            if line.is_empty() { continue; }
+            let not_a_fold = || ParseError::NotAFold { at: Position::of(i, line, line), found: line.to_string() };
+            let (b, number) = line.strip_prefix("fold along ").and_then(|fold| fold.split_once('=')).ok_or_else(not_a_fold)?;
+            let b: bool = match b {
+                "x" => true,
+                "y" => false,
+                _ => return Err(not_a_fold()),
+            };
+            let pos: usize = number.parse().map_err(|_| not_a_fold())?;
+            let size = if b { &mut width } else { &mut height };
+            if pos >= *size {
+                return Err(ParseError::FoldOutside { at: Position::of(i, line, number), size: *size });
+            }
+            *size = max(pos, *size - pos - 1);
+            folds.push((b, pos));
+        }
+        if folds.is_empty() {
+            return Err(ParseError::NoFolds);
+        }
+
+        Ok(Manual { paper, folds })
+    }
+
+    fn part1(manual: &Manual) -> usize {
+        part1(manual.paper.clone(), &manual.folds)
+    }
+
+    fn part2(manual: &Manual) -> Code {
+        Code(part2(manual.paper.clone(), &manual.folds))
+    }
+}
+
+fn fold_paper(paper: Paper, (vert, pos): (bool, usize)) -> Paper {
+    let mut new_paper: Paper;
+    if vert /*vertical crease; fold paper x=pos*/ {        
+        let (side1, side2) = (pos, paper.width() - pos - 1);
+        new_paper = Grid::new(max(side1, side2), paper.height(), false);
+        for i in 0..new_paper.height() {
+            for j in 0..min(side1, side2) {
+                new_paper[(i, j)] = paper[(i, pos-j-1)] || paper[(i, pos+j+1)];
+            }
+        }
+    } else /*horizontal crease; fold paper y=pos*/ {
+        let (side1, side2) = (pos, paper.height() - pos - 1);
+        new_paper = Grid::new(paper.width(), max(side1, side2), false);
+        for i in 0..min(side1, side2) {
+            for j in 0..new_paper.width() {
+                new_paper[(i, j)] = paper[(pos-i-1, j)] || paper[(pos+i+1, j)];
+            }
+        }
+    }
+    new_paper
+}
+
+fn count_dots(p: &Paper) -> usize {
+    p.iter().filter(|&&dot| dot).count()
+}
+
+fn part1(mut paper: Paper, folds: &[(bool, usize)]) -> usize {
+    paper = fold_paper(paper, folds[0]);
+    count_dots(&paper)
+}
+
+// The folds leave the paper upside down and backwards
+fn part2(mut paper: Paper, folds: &[(bool, usize)]) -> Paper {
+    for &fold in folds {
+        paper = fold_paper(paper, fold);
+    }
+    let (w, h) = (paper.width(), paper.height());
+    Grid::from_fn(w, h, |(i, j)| paper[(h-i-1, w-j-1)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_manuals() {
+        let at = |line, column| Position { line, column };
+        assert_eq!(Day13::parse("6,10\nfold along y=7").err(), Some(ParseError::NoBlankLine));
+        assert_eq!(
+            Day13::parse("6,10\n6;10\n\nfold along y=7").err(),
+            Some(ParseError::NotADot { at: at(2, 1), found: "6;10".to_string() })
+        );
+        assert_eq!(
+            Day13::parse("6,99999999999999999999\n\nfold along y=7").err(),
+            Some(ParseError::NotACoordinate { at: at(1, 3), found: "99999999999999999999".to_string() })
+        );
+        assert_eq!(
+            Day13::parse("6,10\n\nfold along z=7").err(),
+            Some(ParseError::NotAFold { at: at(3, 1), found: "fold along z=7".to_string() })
+        );
+        assert_eq!(
+            Day13::parse("6,10\n\nfold along y=7\nfold along y=7").err(),
+            Some(ParseError::FoldOutside { at: at(4, 14), size: 7 })
+        );
+        assert_eq!(Day13::parse("6,10\n\n").err(), Some(ParseError::NoFolds));
+        assert_eq!(Day13::part1(&Day13::parse(Day13::EXAMPLE.unwrap()).unwrap()), 17);
+    }
+}
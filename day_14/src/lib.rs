@@ -0,0 +1,212 @@
+use std::{collections::HashMap, fmt::{Display, Formatter}};
+use common::{Position, Solution};
+
+type Rules = HashMap<String, String>;
+
+pub struct Manual {
+    polymer: String,
+    rules: Rules,
+}
+
+/// What can go wrong reading the polymer manual
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The first line is the polymer template
+    NoTemplate,
+    /// Polymers are made of elements, which are capital letters
+    NotAnElement { at: Position, found: char },
+    /// A blank line comes between the template and the rules
+    NoBlankLine { line: usize },
+    /// Rules look like `AB -> C`
+    NotARule { at: Position, found: String },
+    /// Some pair the polymer grows has no rule for what goes between it,
+    /// `at` the template or rule that makes the pair
+    NoRule { at: Position, pair: String },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NoTemplate => write!(f, "expected a polymer template, found nothing"),
+            ParseError::NotAnElement { at, found } => {
+                write!(f, "{at}: expected an element from `A` to `Z`, found `{found}`")
+            }
+            ParseError::NoBlankLine { line } => {
+                write!(f, "line {line}: expected a blank line after the template")
+            }
+            ParseError::NotARule { at, found } => {
+                write!(f, "{at}: expected a rule like `AB -> C`, found `{found}`")
+            }
+            ParseError::NoRule { at, pair } => {
+                write!(f, "{at}: expected a rule for the pair `{pair}` this makes, found none")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Manual;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 14;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Manual, ParseError> {
+        let lines: Vec<&str> = input.trim_end().lines().collect();
+        let polymer = lines.first().copied().filter(|line| !line.is_empty()).ok_or(ParseError::NoTemplate)?;
+        if let Some((j, c)) = polymer.char_indices().find(|(_, c)| !c.is_ascii_uppercase()) {
+            return Err(ParseError::NotAnElement { at: Position::of(0, polymer, &polymer[j..]), found: c });
+        }
+        match lines.get(1) {
+            Some(line) if !line.trim().is_empty() => return Err(ParseError::NoBlankLine { line: 2 }),
+            _ => {}
+        }
+
+        let mut rules = HashMap::new();
+        // Where each rule's insertion is, to point at when it makes a pair
+        // that has no rule
+        let mut inserted_at = HashMap::new();
+        for (i, &line) in lines.iter().enumerate().skip(2) {
+            let elements = |s: &str, n: usize| s.len() == n && s.chars().all(|c| c.is_ascii_uppercase());
+            let (pair, insertion) = line
+                .split_once(" -> ")
+                .filter(|&(pair, insertion)| elements(pair, 2) && elements(insertion, 1))
+                .ok_or_else(|| ParseError::NotARule { at: Position::of(i, line, line), found: line.to_string() })?;
+            inserted_at.insert(pair.to_string(), Position::of(i, line, insertion));
+            rules.insert(pair.to_string(), insertion.to_string());
+        }
+
+        // Every pair the polymer will ever have needs a rule: the template's
+        // own pairs, and the two each rule turns its pair into, checked in
+        // the order they turn up
+        let mut made_at: HashMap<String, Position> = HashMap::new();
+        let mut pairs = Vec::new();
+        for j in 0..polymer.len().saturating_sub(1) {
+            let pair = &polymer[j..j + 2];
+            if !made_at.contains_key(pair) {
+                made_at.insert(pair.to_string(), Position::of(0, polymer, pair));
+                pairs.push(pair.to_string());
+            }
+        }
+        let mut next = 0;
+        while let Some(pair) = pairs.get(next).cloned() {
+            next += 1;
+            let Some(insertion) = rules.get(&pair) else {
+                return Err(ParseError::NoRule { at: made_at[&pair], pair });
+            };
+            for made in [format!("{}{insertion}", &pair[..1]), format!("{insertion}{}", &pair[1..])] {
+                if !made_at.contains_key(&made) {
+                    made_at.insert(made.clone(), inserted_at[&pair]);
+                    pairs.push(made);
+                }
+            }
+        }
+
+        Ok(Manual { polymer: polymer.to_string(), rules })
+    }
+
+    fn part1(manual: &Manual) -> usize {
+        part1(manual.polymer.clone(), &manual.rules)
+    }
+
+    fn part2(manual: &Manual) -> usize {
+        part2(&manual.polymer, &manual.rules)
+    }
+}
+
+fn part1(mut polymer: String, rules: &Rules) -> usize {
+    for _step in 0..10 {
+        let mut insertions: String = String::with_capacity(polymer.len()-1);
+        let iter = (0..polymer.len()-1).map(|i| polymer[i..i+2].to_string());
+        iter.for_each(|x| insertions.push_str(rules[&x].as_str()));
+        let old = polymer.clone();
+
+        polymer = String::with_capacity(polymer.len()*2-1);
+        for i in 0..old.len() {
+            polymer.push_str(&old[i..i+1]);
+            if i != old.len()-1 {
+                polymer.push_str(&insertions[i..i+1]);
+            }
+        }
+    }
+    
+    let mut count: HashMap<char, usize> = HashMap::new();
+    for c in polymer.chars() {
+        *count.entry(c).or_insert(0) += 1;
+    }
+    
+    count.values().max().unwrap() - count.values().min().unwrap()
+}
+
+fn part2(polymer: &str, rules: &Rules) -> usize {
+    let mut pair_count: HashMap<String, usize> = HashMap::new();
+
+    let iter = (0..polymer.len()-1).map(|i| polymer[i..i+2].to_string());
+    for s in iter {
+        let x = pair_count.entry(s).or_insert(0);
+        *x += 1;
+    }
+
+    for _step in 0..40 {
+        let mut new_count: HashMap<String, usize> = HashMap::new();
+        for (k, v) in pair_count.drain() {
+            let mut k1 = k[..1].to_string();
+            let mut k2 = rules.get(&k).unwrap().clone();
+            k1.push_str(rules.get(&k).unwrap());
+            k2.push_str(&k[1..]);
+
+            let x = new_count.entry(k1).or_insert(0);
+            *x += v;
+            let y = new_count.entry(k2).or_insert(0);
+            *y += v;
+        }
+        pair_count = new_count
+    }
+
+    // Every letter is the first of exactly one pair except the last letter of
+    // the polymer, which never changes
+    let mut count: HashMap<char, usize> = HashMap::with_capacity(26);
+    for (k, v) in pair_count.drain() {
+        let a = count.entry(k.chars().next().unwrap()).or_insert(0);
+        *a += v;
+    }
+    *count.entry(polymer.chars().last().unwrap()).or_insert(0) += 1;
+
+    count.values().max().unwrap() - count.values().min().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_manuals() {
+        assert!(Day14::parse(Day14::EXAMPLE.unwrap()).is_ok());
+        assert_eq!(Day14::parse("").err(), Some(ParseError::NoTemplate));
+        assert_eq!(
+            Day14::parse("NNcB").err(),
+            Some(ParseError::NotAnElement { at: Position { line: 1, column: 3 }, found: 'c' })
+        );
+        assert_eq!(Day14::parse("AB\nAB -> C").err(), Some(ParseError::NoBlankLine { line: 2 }));
+        assert_eq!(
+            Day14::parse("AB\n\nAB => C").err(),
+            Some(ParseError::NotARule { at: Position { line: 3, column: 1 }, found: "AB => C".to_string() })
+        );
+        assert_eq!(
+            Day14::parse("ABA\n\nAB -> A").err(),
+            Some(ParseError::NoRule { at: Position { line: 1, column: 2 }, pair: "BA".to_string() })
+        );
+        // AB makes AC and CB, and there's no rule for AC
+        assert_eq!(
+            Day14::parse("AB\n\nAB -> C\nCB -> B\nBB -> B").err(),
+            Some(ParseError::NoRule { at: Position { line: 3, column: 7 }, pair: "AC".to_string() })
+        );
+        // A template of one element never grows
+        assert_eq!(Day14::part2(&Day14::parse("A").unwrap()), 0);
+    }
+}
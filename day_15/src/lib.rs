@@ -0,0 +1,109 @@
+// Alright, I don't have the will to do this right now,
+// but I think I know how to do what Dalon is doing
+// 1. Keep a list of what hasn't been visited (find out what a binaryheap is)
+//    Actually, I bet it is like a vector but it pushes onto the left.
+//    (That is not what a binary heap is)
+// 2. From the starting point, update distances
+// 3. If you updated it and it had already been visited, mark it to be
+//    visited again.
+// 4. Continue doing this until there is nothing left to visit
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+use common::Solution;
+use grid::Grid;
+
+#[derive(Hash, Copy, Clone, Eq, PartialEq)]
+pub struct Point {
+    p: (usize, usize),
+}
+
+// Because of how the cmp method is designed, the Points that are closer to
+// the origin will be popped off the BinaryHeap first. This should eliminate
+// some backtracking.
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.p.0 + other.p.1).cmp(&(self.p.0 + self.p.1))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub type Map = Grid<u8>;
+type Distances = Grid<i32>;
+type Queue = BinaryHeap<Point>;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Map;
+    type Error = grid::ParseError;
+    type Answer1 = i32;
+    type Answer2 = i32;
+    const DAY: u8 = 15;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(raw: &str) -> Result<Map, grid::ParseError> {
+        raw.parse()
+    }
+
+    fn part1(m: &Map) -> i32 {
+        part1(m)
+    }
+
+    fn part2(m: &Map) -> i32 {
+        part2(m)
+    }
+}
+
+fn dijkstra(p: Point, m: &Map, dist: &mut Distances, q: &mut Queue) {
+    for neighbor in m.neighbors4(p.p) {
+        // For each neighbor, find the total distance required to reach it
+        // from the current state with the current distance traveled
+        let alt = dist[p.p] + m[neighbor] as i32;
+        if alt < dist[neighbor] {
+            // If the alternative distance of moving to the next state from
+            // the current state is less than the previously believed
+            // least distance, then change the least distance and add the
+            // next state to the queue
+            dist[neighbor] = alt;
+            q.push(Point{p:neighbor});
+        }
+    }
+}
+
+fn part1(m: &Map) -> i32 {
+    // Dijkstra's algorithm (kinda sorta)
+    // Put one thing into the queue
+    let mut q: Queue = BinaryHeap::from([Point{p:(0,0)}]);
+    // Set all distances to the maximum
+    let mut d: Distances = Grid::new(m.width(), m.height(), i32::MAX);
+
+    // Set the least distance of starting point to 0
+    d[(0,0)] = 0;
+    
+    // While there are still things in the queue, continue to explore the
+    // graph. New Points will be added to the graph as Points are visited and
+    // shown to have a smaller distance than previously believed.
+    while let Some(p) = q.pop() {
+        dijkstra(p, m, &mut d, &mut q);
+    }
+
+    d[(m.height()-1, m.width()-1)]
+}
+
+fn part2(m: &Map) -> i32 {
+    let (w, h) = (m.width(), m.height());
+    let big_map = Grid::from_fn(w*5, h*5, |(i, j)| {
+        let mut val = m[(i % h, j % w)] as usize + (i / h) + (j / w);
+        while val > 9 {
+            val -= 9;
+        }
+        val as u8
+    });
+
+    part1(&big_map)
+}
@@ -0,0 +1,318 @@
+use std::fmt::{Display, Formatter};
+use common::{Position, Solution};
+
+/// A packet and every packet inside it
+#[derive(Debug, Eq, PartialEq)]
+pub struct Packet {
+    pub version: usize,
+    pub body: Body,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Body {
+    Literal(usize),
+    /// `kind` is the packet's type ID, which says what to do with the
+    /// packets inside
+    Operator { kind: usize, packets: Vec<Packet> },
+}
+
+/// What can go wrong reading the transmission. Everything is on the one
+/// line, and the column is the hex digit the bit in question is in.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    NotHex { at: Position, found: char },
+    /// A packet needs more bits than there are
+    Truncated { at: Position },
+    /// The packets inside an operator run past the bits it has for them
+    Overrun { at: Position },
+    /// An operator with the wrong number of packets for its type
+    Operands { at: Position, kind: usize, found: usize },
+    /// A literal too big for a `usize`
+    LiteralTooBig { at: Position },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotHex { at, found } => write!(f, "{at}: expected a hex digit, found `{found}`"),
+            ParseError::Truncated { at } => write!(f, "{at}: expected more of the packet, found the end"),
+            ParseError::Overrun { at } => {
+                write!(f, "{at}: expected the operator's packets to end, found one still going")
+            }
+            ParseError::Operands { at, kind, found } => {
+                let expected = if *kind >= 5 { "2 packets" } else { "at least 1 packet" };
+                write!(f, "{at}: expected {expected} for an operator of type {kind}, found {found}")
+            }
+            ParseError::LiteralTooBig { at } => write!(f, "{at}: expected a literal that fits in a usize"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Where bit `bit` of the transmission is
+fn position(bit: usize) -> Position {
+    Position { line: 1, column: bit / 4 + 1 }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 16;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(raw: &str) -> Result<Packet, ParseError> {
+        let raw = raw.trim();
+        let mut v = vec![0u8; raw.len().div_ceil(2)];
+        for (i, c) in raw.char_indices() {
+            let digit = c.to_digit(16).ok_or(ParseError::NotHex { at: Position::of(0, raw, &raw[i..]), found: c })?;
+            v[i / 2] |= (digit as u8) << (4 - i % 2 * 4);
+        }
+        let mut pos = 0usize;
+        read_packet(&v, raw.len() * 4, &mut pos)
+    }
+
+    fn part1(packet: &Packet) -> usize {
+        sum_versions(packet)
+    }
+
+    fn part2(packet: &Packet) -> usize {
+        evaluate_packet(packet)
+    }
+}
+
+/// The next `n` bits of the `len` there are, or `Truncated` if they run out
+fn read(v: &[u8], len: usize, pos: &mut usize, n: usize) -> Result<usize, ParseError> {
+    if *pos + n > len {
+        return Err(ParseError::Truncated { at: position(len) });
+    }
+    Ok(read_bits(v, pos, *pos + n - 1))
+}
+
+fn read_packet(v: &[u8], len: usize, pos: &mut usize) -> Result<Packet, ParseError> {
+    let start = *pos;
+    let version = read(v, len, pos, 3)?;
+    let kind = read(v, len, pos, 3)?;
+
+    if kind == 4 {
+        let mut cont = 1;
+        let mut lit: usize = 0;
+        while cont != 0 {
+            cont = read(v, len, pos, 1)?;
+            if lit.leading_zeros() < 4 {
+                return Err(ParseError::LiteralTooBig { at: position(start) });
+            }
+            lit = (lit << 4) + read(v, len, pos, 4)?;
+        }
+        return Ok(Packet { version, body: Body::Literal(lit) });
+    }
+
+    let mut packets = Vec::new();
+    if read(v, len, pos, 1)? == 0 {
+        let n_bits = read(v, len, pos, 15)?;
+        let end = *pos + n_bits;
+        while *pos < end {
+            packets.push(read_packet(v, len, pos)?);
+        }
+        if *pos > end {
+            return Err(ParseError::Overrun { at: position(end) });
+        }
+    } else {
+        let n_packets = read(v, len, pos, 11)?;
+        for _ in 0..n_packets {
+            packets.push(read_packet(v, len, pos)?);
+        }
+    }
+
+    let enough = match kind {
+        0..=3 => !packets.is_empty(),
+        _ => packets.len() == 2,
+    };
+    if !enough {
+        return Err(ParseError::Operands { at: position(start), kind, found: packets.len() });
+    }
+    Ok(Packet { version, body: Body::Operator { kind, packets } })
+}
+
+fn sum_versions(packet: &Packet) -> usize {
+    match &packet.body {
+        Body::Literal(_) => packet.version,
+        Body::Operator { packets, .. } => packet.version + packets.iter().map(sum_versions).sum::<usize>(),
+    }
+}
+
+fn evaluate_packet(packet: &Packet) -> usize {
+    let (kind, packets) = match &packet.body {
+        Body::Literal(lit) => return *lit,
+        Body::Operator { kind, packets } => (*kind, packets),
+    };
+    let vals: Vec<usize> = packets.iter().map(evaluate_packet).collect();
+
+    if kind == 0 {
+        vals.into_iter().sum::<usize>()
+    } else if kind == 1 {
+        vals.into_iter().product::<usize>()
+    } else if kind == 2 {
+        vals.into_iter().min().unwrap()
+    } else if kind == 3 {
+        vals.into_iter().max().unwrap()
+    } else if kind == 5 {
+        (vals[0] > vals[1]) as usize
+    } else if kind == 6 {
+        (vals[0] < vals[1]) as usize
+    } else {
+        (vals[0] == vals[1]) as usize
+    }
+}
+
+fn read_bits(v: &[u8], sbit: &mut usize, ebit: usize) -> usize {
+
+    let mut value = 0usize;
+    
+    while *sbit <= ebit {
+        let dist = 7 - *sbit % 8;
+        value = (value << 1) + ((v[*sbit >> 3] & 1 << dist) >> dist) as usize;
+        *sbit += 1;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_byte() {
+        let x = vec![255u8];
+        let y = vec![0u8];
+        let z = vec![85u8]; // 0b01010101
+
+        assert_eq!(read_bits(&x, &mut 3, 4), 3);
+        assert_eq!(read_bits(&y, &mut 3, 4), 0);
+        assert_eq!(read_bits(&z, &mut 3, 4), 2);
+    }
+
+    #[test]
+    fn two_bytes() {
+        let x = vec![255u8, 255];
+        let y = vec![0u8, 0];
+        let z = vec![85u8, 85]; // 0b01010101
+
+        assert_eq!(read_bits(&x, &mut 6, 9), 15);
+        assert_eq!(read_bits(&y, &mut 6, 9), 0);
+        assert_eq!(read_bits(&z, &mut 6, 9), 5);
+    }
+
+    #[test]
+    fn one_packet() {
+        let packet = Day16::parse("D2FE28").unwrap();
+        assert_eq!(packet, Packet { version: 6, body: Body::Literal(2021) });
+        assert_eq!(sum_versions(&packet), 6);
+    }
+
+    #[test]
+    fn three_packets() {
+        let packet = Day16::parse("38006F45291200").unwrap();
+        let x = sum_versions(&packet);
+        assert_eq!(x, 9);
+    }
+
+    #[test]
+    fn four_packets() {
+        let packet = Day16::parse("EE00D40C823060").unwrap();
+        let x = sum_versions(&packet);
+        assert_eq!(x, 14);
+    }
+
+    #[test]
+    fn part1_examples() {
+        let v1 = Day16::parse("8A004A801A8002F478").unwrap();
+        let v2 = Day16::parse("620080001611562C8802118E34").unwrap();
+        let v3 = Day16::parse("C0015000016115A2E0802F182340").unwrap();
+
+        assert_eq!(sum_versions(&v1), 16);
+        assert_eq!(sum_versions(&v2), 12);
+        assert_eq!(sum_versions(&v3), 23);
+    }
+
+    #[test]
+    fn addition() {
+        let packet = Day16::parse("C200B40A82").unwrap();
+        let x = evaluate_packet(&packet);
+        assert_eq!(x, 3);
+    }
+
+    #[test]
+    fn multiplication() {
+        let packet = Day16::parse("04005AC33890").unwrap();
+        let x = evaluate_packet(&packet);
+        assert_eq!(x, 54);
+    }
+
+    #[test]
+    fn minimum() {
+        let packet = Day16::parse("880086C3E88112").unwrap();
+        let x = evaluate_packet(&packet);
+        assert_eq!(x, 7);
+    }
+
+    #[test]
+    fn maximum() {
+        let packet = Day16::parse("CE00C43D881120").unwrap();
+        let x = evaluate_packet(&packet);
+        assert_eq!(x, 9);
+    }
+
+    #[test]
+    fn greater_than() {
+        let packet = Day16::parse("F600BC2D8F").unwrap();
+        let x = evaluate_packet(&packet);
+        assert_eq!(x, 0);
+    }
+
+    #[test]
+    fn less_than() {
+        let packet = Day16::parse("D8005AC2A8F0").unwrap();
+        let x = evaluate_packet(&packet);
+        assert_eq!(x, 1);
+    }
+
+    #[test]
+    fn equal_to() {
+        let packet = Day16::parse("9C005AC2F8F0").unwrap();
+        let x = evaluate_packet(&packet);
+        assert_eq!(x, 0);
+    }
+
+    #[test]
+    fn all_together() {
+        let packet = Day16::parse("9C0141080250320F1802104A08").unwrap();
+        let x = evaluate_packet(&packet);
+        assert_eq!(x, 1);
+    }
+
+    #[test]
+    fn bad_transmissions() {
+        let at = |column| Position { line: 1, column };
+        assert_eq!(Day16::parse("00").err(), Some(ParseError::Truncated { at: at(3) }));
+        assert_eq!(Day16::parse("").err(), Some(ParseError::Truncated { at: at(1) }));
+        assert_eq!(Day16::parse("D2FG28").err(), Some(ParseError::NotHex { at: at(4), found: 'G' }));
+        // A sum of nothing, with its packets 0 bits long
+        assert_eq!(Day16::parse("000000").err(), Some(ParseError::Operands { at: at(1), kind: 0, found: 0 }));
+        // Less than, of just the one literal
+        assert_eq!(
+            Day16::parse("DA004408").err(),
+            Some(ParseError::Operands { at: at(1), kind: 6, found: 1 })
+        );
+        // The sum's 10 bits are too few for its literal, which takes 11
+        assert_eq!(Day16::parse("800028408").err(), Some(ParseError::Overrun { at: at(9) }));
+        assert_eq!(
+            Day16::parse("D3FFFFFFFFFFFFFFFFFFFC0").err(),
+            Some(ParseError::LiteralTooBig { at: at(1) })
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt::{Display, Formatter};
+use common::{Position, Solution};
+
+type Bounds = ((i32, i32), (i32, i32));
+type Vector = (i32, i32);
+
+/// What can go wrong reading the target area
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Something other than what the target area needs at that point
+    Expected { at: Position, expected: &'static str, found: String },
+    /// A range like `x=30..20` that ends before it starts
+    Backwards { at: Position, found: String },
+    /// The target has to be ahead of the probe and below it
+    Behind { at: Position },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::Expected { at, expected, found } => {
+                write!(f, "{at}: expected {expected}, found `{found}`")
+            }
+            ParseError::Backwards { at, found } => {
+                write!(f, "{at}: expected a range that ends after it starts, found `{found}`")
+            }
+            ParseError::Behind { at } => {
+                write!(f, "{at}: expected a target ahead of the probe and below it")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Bounds;
+    type Error = ParseError;
+    type Answer1 = i32;
+    type Answer2 = i32;
+    const DAY: u8 = 17;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    // target area: x=56..76, y=-162..-134
+    fn parse(input: &str) -> Result<Bounds, ParseError> {
+        let line = input.trim();
+        let expected = |token: &str, expected| ParseError::Expected {
+            at: Position::of(0, line, token),
+            expected,
+            found: token.to_string(),
+        };
+        let x = line.strip_prefix("target area: x=").ok_or_else(|| expected(line, "`target area: x=`"))?;
+        let (x, y) = x.split_once(", y=").ok_or_else(|| expected(x, "a range for x then `, y=`"))?;
+        let range = |s: &str| -> Result<(i32, i32), ParseError> {
+            let (start, end) = s.split_once("..").ok_or_else(|| expected(s, "a range like `20..30`"))?;
+            let number = |n: &str| n.parse::<i32>().map_err(|_| expected(n, "a number"));
+            let (start, end) = (number(start)?, number(end)?);
+            if start > end {
+                return Err(ParseError::Backwards { at: Position::of(0, line, s), found: s.to_string() });
+            }
+            Ok((start, end))
+        };
+        let (x_range, y_range) = (range(x)?, range(y)?);
+        if x_range.0 <= 0 {
+            return Err(ParseError::Behind { at: Position::of(0, line, x) });
+        }
+        if y_range.1 >= 0 {
+            return Err(ParseError::Behind { at: Position::of(0, line, y) });
+        }
+        Ok((x_range, y_range))
+    }
+
+    // The probe comes back down through y = 0 going one faster than it went
+    // up, so the fastest we can launch it still hits the bottom of the target
+    fn part1(bounds: &Bounds) -> i32 {
+        let v = -bounds.1.0 - 1;
+        v * (v + 1) / 2
+    }
+
+    fn part2(bounds: &Bounds) -> i32 {
+        part2(*bounds)
+    }
+}
+
+fn part2(bounds: Bounds) -> i32 {
+
+    let mut count = 0;
+    
+    for x in 1..=(bounds.0.1) {
+        for y in (bounds.1.0)..(-bounds.1.0) {
+            count += acertou((x,y), bounds);
+        }
+    }
+
+    count
+}
+
+fn acertou(mut vel: Vector, bounds: Bounds) -> i32 {
+
+    let mut pos = (0,0);
+
+    loop {
+
+        // Check
+        if pos.0 > bounds.0.1 || pos.1 < bounds.1.0 {
+            return 0;
+        }
+
+        if pos.0 >= bounds.0.0 && pos.1 <= bounds.1.1 {
+            return 1;
+        }
+
+        // Step
+        pos = (pos.0 + vel.0, pos.1 + vel.1);
+        vel = (std::cmp::max(0, vel.0 - 1), vel.1 - 1);
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_targets() {
+        let at = |column| Position { line: 1, column };
+        assert_eq!(Day17::parse(Day17::EXAMPLE.unwrap()), Ok(((20, 30), (-10, -5))));
+        assert_eq!(
+            Day17::parse("target x=20..30, y=-10..-5").err(),
+            Some(ParseError::Expected { at: at(1), expected: "`target area: x=`", found: "target x=20..30, y=-10..-5".to_string() })
+        );
+        assert_eq!(
+            Day17::parse("target area: x=20..3o, y=-10..-5").err(),
+            Some(ParseError::Expected { at: at(20), expected: "a number", found: "3o".to_string() })
+        );
+        assert_eq!(
+            Day17::parse("target area: x=30..20, y=-10..-5").err(),
+            Some(ParseError::Backwards { at: at(16), found: "30..20".to_string() })
+        );
+        assert_eq!(Day17::parse("target area: x=-30..-20, y=-10..-5").err(), Some(ParseError::Behind { at: at(16) }));
+        assert_eq!(Day17::parse("target area: x=20..30, y=-10..5").err(), Some(ParseError::Behind { at: at(26) }));
+    }
+}
@@ -1,7 +1,39 @@
 
 pub mod lib {
 
-    use std::ops::Add;
+    use std::{fmt::{Display, Formatter}, ops::Add};
+
+    use common::Position;
+
+    /// What can go wrong reading SnailFish numbers
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum ParseError {
+        /// Something else turned up, or nothing if `found` is empty
+        Expected { at: Position, expected: &'static str, found: String },
+        /// There is nothing to add up
+        NoNumbers,
+    }
+
+    impl ParseError {
+        fn expected(index: usize, line: &str, pos: usize, expected: &'static str) -> Self {
+            let found = line[pos..].chars().next().map(String::from).unwrap_or_default();
+            ParseError::Expected { at: Position::of(index, line, &line[pos..]), expected, found }
+        }
+    }
+
+    impl Display for ParseError {
+        fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+            match self {
+                ParseError::Expected { at, expected, found } if found.is_empty() => {
+                    write!(f, "{at}: expected {expected}, found the end of the line")
+                }
+                ParseError::Expected { at, expected, found } => write!(f, "{at}: expected {expected}, found `{found}`"),
+                ParseError::NoNumbers => write!(f, "expected SnailFish numbers, found none"),
+            }
+        }
+    }
+
+    impl std::error::Error for ParseError {}
 
     //                    type, pair_ind, num_ind
     #[derive(Debug)]
@@ -26,27 +58,51 @@ pub mod lib {
     /// I. HAVE. NO. REGRETS.
     impl SnailFish {
         
-        pub fn new(s: &str) -> Self {
+        pub fn new(s: &str) -> Result<Self, ParseError> {
+            Self::parse_line(0, s)
+        }
+
+        /// The SnailFish number on the `index`th line of the input
+        pub fn parse_line(index: usize, line: &str) -> Result<Self, ParseError> {
             let mut pos = 0usize;
-            Self::new_recurse(s, &mut pos)
+            if !line.starts_with('[') {
+                return Err(ParseError::expected(index, line, pos, "`[`"));
+            }
+            let number = Self::new_recurse(index, line, &mut pos)?;
+            if pos < line.len() {
+                return Err(ParseError::expected(index, line, pos, "the end of the line"));
+            }
+            Ok(number)
         }
-    
-        pub fn new_recurse(s: &str, pos: &mut usize) -> Self {
-            loop {
-                match &s[(*pos)..(*pos+1)] {
-                    "[" => { *pos += 1;
-                        return Pair(
-                            Box::new(Self::new_recurse(s, pos)),
-                            Box::new(Self::new_recurse(s, pos))
-                            );
-                    },
-                    "," => { *pos += 1; },
-                    "]" => { *pos += 1; },
-                    _ => { *pos += 1; break Number(s[(*pos-1)..(*pos)].parse().unwrap()) }
+
+        fn new_recurse(index: usize, line: &str, pos: &mut usize) -> Result<Self, ParseError> {
+            let expect = |pos: &mut usize, c: char| {
+                if line[*pos..].starts_with(c) {
+                    *pos += 1;
+                    Ok(())
+                } else {
+                    Err(ParseError::expected(index, line, *pos, if c == ',' { "`,`" } else { "`]`" }))
+                }
+            };
+            match line[*pos..].chars().next() {
+                Some('[') => {
+                    *pos += 1;
+                    let left = Self::new_recurse(index, line, pos)?;
+                    expect(pos, ',')?;
+                    let right = Self::new_recurse(index, line, pos)?;
+                    expect(pos, ']')?;
+                    Ok(Pair(Box::new(left), Box::new(right)))
+                }
+                Some('0'..='9') => {
+                    let start = *pos;
+                    *pos += line[start..].find(|c: char| !c.is_ascii_digit()).unwrap_or(line.len() - start);
+                    line[start..*pos].parse().map(Number).map_err(|_| ParseError::expected(index, line, start, "a smaller number"))
                 }
+                _ => Err(ParseError::expected(index, line, *pos, "`[` or a number")),
             }
         }
-        
+
+        #[allow(clippy::len_without_is_empty)] // there is no empty SnailFish
         pub fn len(&self) -> usize {
             match self {
                 Number(_) => 1,
@@ -82,7 +138,7 @@ pub mod lib {
             match self {
                 Number(_) => {
                     if ind == *i {
-                        Some(&self) 
+                        Some(self) 
                     } else { 
                         *i += 1;
                         None 
@@ -216,7 +272,6 @@ pub mod lib {
                     }
                 }
             }
-            ()
         }
 
         fn check_explode(&self, pair_i: &mut i32, num_i: &mut i32, depth: i32) -> Result<(), ReduceAction> {
@@ -311,6 +366,58 @@ pub mod lib {
     }
 }
 
+use common::Solution;
+use crate::lib::{ParseError, SnailFish};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<SnailFish>;
+    type Error = ParseError;
+    type Answer1 = i32;
+    type Answer2 = i32;
+    const DAY: u8 = 18;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(raw: &str) -> Result<Vec<SnailFish>, ParseError> {
+        let mut pairs = Vec::new();
+        for (i, line) in raw.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+            pairs.push(SnailFish::parse_line(i, line)?);
+        }
+        if pairs.is_empty() {
+            return Err(ParseError::NoNumbers);
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Vec<SnailFish>) -> i32 {
+        part1(pairs.clone())
+    }
+
+    fn part2(pairs: &Vec<SnailFish>) -> i32 {
+        part2(pairs.clone())
+    }
+}
+
+fn part1(pairs: Vec<SnailFish>) -> i32 {
+    pairs.into_iter().reduce(|left, right| left + right ).unwrap().magnitude()
+}
+
+fn part2(pairs: Vec<SnailFish>) -> i32 {
+
+    let mut ans = 0;
+    // Adding SnailFish isn't commutative so both orders have to be tried
+    for i in 0..pairs.len() {
+        for j in (0..pairs.len()).filter(|&j| j != i) {
+            let res = (pairs[i].clone() + pairs[j].clone()).magnitude();
+            if res > ans {
+                ans = res;
+            }
+        }
+    }
+    ans
+}
+
 #[cfg(test)]
 mod tests {
     use crate::lib::SnailFish;
@@ -318,27 +425,27 @@ mod tests {
 
     #[test]
     fn count_numbers() {
-        let test = SnailFish::new("[[[[0,7],5],[[5,7],2]],[[2,[9,5]],[[7,7],[5,0]]]]");
+        let test = SnailFish::new("[[[[0,7],5],[[5,7],2]],[[2,[9,5]],[[7,7],[5,0]]]]").unwrap();
         assert_eq!(test.len(), 13);
     }
 
     #[test]
     fn count_pairs() {
-        let test = SnailFish::new("[[[[0,7],5],[[5,7],2]],[[2,[9,5]],[[7,7],[5,0]]]]");
+        let test = SnailFish::new("[[[[0,7],5],[[5,7],2]],[[2,[9,5]],[[7,7],[5,0]]]]").unwrap();
         assert_eq!(test.pairs(), 12);
     }
 
     #[test]
     fn add_snailfish() {
-        let num1 = SnailFish::new("[1,2]");
-        let num2 = SnailFish::new("[3,4]");
+        let num1 = SnailFish::new("[1,2]").unwrap();
+        let num2 = SnailFish::new("[3,4]").unwrap();
         let num3 = num1 + num2;
-        assert_eq!(num3, SnailFish::new("[[1,2],[3,4]]"));
+        assert_eq!(num3, SnailFish::new("[[1,2],[3,4]]").unwrap());
     }
 
     #[test]
     fn get_snailfish() {
-        let pair = SnailFish::new("[1,[2,3]]");
+        let pair = SnailFish::new("[1,[2,3]]").unwrap();
         assert_eq!(pair.get(-1), None);
         assert_eq!(pair.get(0), Some(&Number(1)));
         assert_eq!(pair.get(1), Some(&Number(2)));
@@ -348,32 +455,32 @@ mod tests {
 
     #[test]
     fn get_mut_snailfish() {
-        let mut pair = SnailFish::new("[1,[2,3]]");
+        let mut pair = SnailFish::new("[1,[2,3]]").unwrap();
         let num = pair.get_mut(0).unwrap();
         *num = Pair(Box::new(Number(4)),Box::new(Number(5)));
-        assert_eq!(pair, SnailFish::new("[[4,5],[2,3]]"));
+        assert_eq!(pair, SnailFish::new("[[4,5],[2,3]]").unwrap());
     }
 
     #[test]
     fn get_pair_snailfish() {
-        let test = SnailFish::new("[[[[0,7],5],[[5,7],2]],[[2,[9,5]],[[7,7],[5,0]]]]");
+        let test = SnailFish::new("[[[[0,7],5],[[5,7],2]],[[2,[9,5]],[[7,7],[5,0]]]]").unwrap();
         let res = test.get_pair(4);
-        assert_eq!(res, Some(&SnailFish::new("[[5,7],2]")));
+        assert_eq!(res, Some(&SnailFish::new("[[5,7],2]").unwrap()));
     }
 
     #[test]
     fn get_pair_mut_snailfish() {
-        let mut test = SnailFish::new("[[[[0,7],5],[[5,7],2]],[[2,[9,5]],[[7,7],[5,0]]]]");
+        let mut test = SnailFish::new("[[[[0,7],5],[[5,7],2]],[[2,[9,5]],[[7,7],[5,0]]]]").unwrap();
         let victim = test.get_pair_mut(6).unwrap();
         *victim = Number(0);
-        assert_eq!(test, SnailFish::new("[[[[0,7],5],[[5,7],2]],0]"));
+        assert_eq!(test, SnailFish::new("[[[[0,7],5],[[5,7],2]],0]").unwrap());
     }
 
     #[test]
     fn explode_snailfish() {
-        let mut test = SnailFish::new("[[5,[[3,[1,2]],4]],6]");
+        let mut test = SnailFish::new("[[5,[[3,[1,2]],4]],6]").unwrap();
         test.explode(4, 2);
-        assert_eq!(test, SnailFish::new("[[5,[[4,0],6]],6]"));
+        assert_eq!(test, SnailFish::new("[[5,[[4,0],6]],6]").unwrap());
     }
 
     #[test]
@@ -382,14 +489,14 @@ mod tests {
         // function to recognize single digit numbers :/
         let mut pair = Pair(Box::new(Number(15)),Box::new(Number(4)));
         pair.split(0);
-        assert_eq!(pair, SnailFish::new("[[7,8],4]"));
+        assert_eq!(pair, SnailFish::new("[[7,8],4]").unwrap());
     }
 
     #[test]
     fn reduce_snailfish() {
-        let left = SnailFish::new("[[[[4,3],4],4],[7,[[8,4],9]]]");
-        let right = SnailFish::new("[1,1]");
-        assert_eq!(left + right, SnailFish::new("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
+        let left = SnailFish::new("[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap();
+        let right = SnailFish::new("[1,1]").unwrap();
+        assert_eq!(left + right, SnailFish::new("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap());
     }
 
     #[test]
@@ -406,10 +513,27 @@ mod tests {
             "[[[5,[7,4]],7],1]",
             "[[[[4,2],2],6],[8,7]]"
         ];
-        let pairs: Vec<SnailFish> = input.into_iter().map(|s| SnailFish::new(s)).collect();
+        let pairs: Vec<SnailFish> = input.into_iter().map(|s| SnailFish::new(s).unwrap()).collect();
         assert_eq!(
             pairs.into_iter().reduce(|left, right| left + right).unwrap(),
-            SnailFish::new("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")
+            SnailFish::new("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").unwrap()
             );
     }
+
+    #[test]
+    fn bad_numbers() {
+        use crate::{lib::ParseError, Day18};
+        use common::{Position, Solution};
+
+        let expected = |column, expected, found: &str| {
+            Some(ParseError::Expected { at: Position { line: 2, column }, expected, found: found.to_string() })
+        };
+        assert_eq!(Day18::parse("[1,2]\n[1,").err(), expected(4, "`[` or a number", ""));
+        assert_eq!(Day18::parse("[1,2]\n[1,2]]").err(), expected(6, "the end of the line", "]"));
+        assert_eq!(Day18::parse("[1,2]\n[1 2]").err(), expected(3, "`,`", " "));
+        assert_eq!(Day18::parse("[1,2]\n7").err(), expected(1, "`[`", "7"));
+        assert_eq!(Day18::parse("[1,2]\n[1,99999999999]").err(), expected(4, "a smaller number", "9"));
+        assert_eq!(Day18::parse("\n").err(), Some(ParseError::NoNumbers));
+        assert_eq!(Day18::parse("[10,2]").unwrap()[0].magnitude(), 34);
+    }
 }
@@ -1,6 +1,7 @@
 
 mod parts;
 pub use parts::{part1, part2};
+use common::{Position, Solution};
 
 // X rotation
 // [   1      0       0    ]
@@ -22,7 +23,7 @@ pub use parts::{part1, part2};
 // This stacks columns.
 // let arr2 = ndarray::concatenate(Axis(1), &[v.view(), v2.view()]).unwrap();
 use ndarray::{s, arr2, Array2, ArrayBase, Axis, concatenate, Dim, SliceArg};
-use std::{collections::{HashSet, HashMap}, ops::{Add, Sub}};
+use std::{collections::{HashSet, HashMap}, fmt::{Display, Formatter}, ops::{Add, Sub}};
 
 type Rotation = [[i32; 3]; 3];
 
@@ -134,13 +135,69 @@ pub fn rotate_z(scanner: &Matrix) -> Matrix {
     scanner.dot(&Z_ROTATION.as_slice().into())
 }
 
+/// What can go wrong reading the scanner reports
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Each beacon is an `x,y,z` triple
+    NotABeacon { line: usize, found: String },
+    /// The coordinates of a beacon are whole numbers
+    NotACoordinate { at: Position, found: String },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotABeacon { line, found } => {
+                write!(f, "line {line}: expected a beacon like `x,y,z`, found `{found}`")
+            }
+            ParseError::NotACoordinate { at, found } => {
+                write!(f, "{at}: expected a coordinate, found `{found}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Matrix>; // A better name for this would be beacons probably but it's too late now
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = i32;
+    const DAY: u8 = 19;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Vec<Matrix>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(scanners: &Vec<Matrix>) -> usize {
+        let (beacons, _scanners) = part1(scanners.clone());
+        beacons.dim().0
+    }
+
+    // The scanner positions only come out of matching them all up in part 1
+    fn part2(scanners: &Vec<Matrix>) -> i32 {
+        let (_beacons, mut scanners) = part1(scanners.clone());
+        let scanners: Vec<_> = scanners.drain().map(|(_, m)| m).collect();
+        let slice = scanners.iter().map(|m| m.0.view()).collect::<Vec<_>>();
+        let map = Matrix(concatenate(Axis(0), slice.as_slice()).unwrap());
+        part2(map)
+    }
+}
+
 pub fn read_input(path: &str) -> Vec<Matrix> {
+    parse_input(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+pub fn parse_input(raw: &str) -> Result<Vec<Matrix>, ParseError> {
 
-    let raw = std::fs::read_to_string(path).unwrap();
     let mut scanners = Vec::with_capacity(27);
 
     let mut scanner = Vec::new();
-    for line in raw.lines() {
+    for (i, line) in raw.lines().enumerate() {
 
         if line.is_empty() {
             scanners.push(std::mem::take(&mut scanner).into());
@@ -153,17 +210,29 @@ pub fn read_input(path: &str) -> Vec<Matrix> {
             }
         } 
         
+        if line.split(',').count() != 3 {
+            return Err(ParseError::NotABeacon { line: i + 1, found: line.to_string() });
+        }
+
         line.split(',')
             .try_for_each(|x| {
 
-            scanner.push(x.parse()?);
+            scanner.push(x.trim().parse().map_err(|_| ParseError::NotACoordinate {
+                at: Position::of(i, line, x.trim()),
+                found: x.trim().to_string(),
+            })?);
             
-            Ok::<(),std::num::ParseIntError>(())
+            Ok::<(),ParseError>(())
 
-        }).unwrap();
+        })?;
     }
 
-    scanners
+    // In case the input doesn't end in a blank line
+    if !scanner.is_empty() {
+        scanners.push(scanner.into());
+    }
+
+    Ok(scanners)
 }
 
 
@@ -305,8 +374,8 @@ impl Iterator for Orientations {
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.face {
-            0 | 1 | 2  => match self.r_z {
-                0 | 1 | 2 => {
+            0..=2 => match self.r_z {
+                0..=2 => {
                     let val = Some(self.scanner.clone());
                     self.scanner = rotate_z(&self.scanner);
                     self.r_z += 1;
@@ -322,7 +391,7 @@ impl Iterator for Orientations {
                 _ => unreachable!(),
             },
             3 => match self.r_z {
-                0 | 1 | 2 => {
+                0..=2 => {
                     let val = Some(self.scanner.clone());
                     self.scanner = rotate_z(&self.scanner);
                     self.r_z += 1;
@@ -338,7 +407,7 @@ impl Iterator for Orientations {
                 _ => unreachable!(),
             },
             4 => match self.r_z {
-                0 | 1 | 2 => {
+                0..=2 => {
                     let val = Some(self.scanner.clone());
                     self.r_z += 1;
                     self.scanner = rotate_z(&self.scanner);
@@ -421,7 +490,7 @@ mod tests {
         let mut rng = thread_rng();
         let mut found = HashSet::new();
 
-        while nums.len() != 0 {
+        while !nums.is_empty() {
             let new = rng.gen_range(0..nums.len());
             nums.remove(new);
             found.insert(original_position(new, &found));
@@ -460,8 +529,7 @@ mod tests {
             .into_iter()
             .flatten()
             .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
+            .into();
         assert_eq!(answer, expected);
 
     }
@@ -496,8 +564,7 @@ mod tests {
             .into_iter()
             .flatten()
             .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
+            .into();
         assert_eq!(answer, expected);
     }
 
@@ -549,6 +616,21 @@ mod tests {
         assert_eq!(count, 24);
     }
 
+    #[test]
+    fn test_bad_input() {
+        let raw = "--- scanner 0 ---\n404,-588,-901\n528,-643\n";
+        assert_eq!(
+            parse_input(raw).unwrap_err(),
+            ParseError::NotABeacon { line: 3, found: "528,-643".to_string() },
+        );
+
+        let raw = "--- scanner 0 ---\n404,-588,-901\n528,-6x3,409\n";
+        assert_eq!(
+            parse_input(raw).unwrap_err(),
+            ParseError::NotACoordinate { at: Position { line: 3, column: 5 }, found: "-6x3".to_string() },
+        );
+    }
+
     #[test]
     fn test_part1() {
         let scanners = read_input("example.txt"); // A better name for this would be beacons probably but it's too late now
@@ -6,6 +6,8 @@ use crate::{
 
 pub fn part1(mut scanners: Vec<Matrix>) -> (Matrix, HashMap<usize, Matrix>) {
 
+    common::debug!(1, "Number of scanners: {}", scanners.len());
+
     // Data structure onto which we can impose rotations
     let mut distances: Vec<Matrix> = scanners
         .iter()
@@ -30,7 +32,7 @@ pub fn part1(mut scanners: Vec<Matrix>) -> (Matrix, HashMap<usize, Matrix>) {
         if scanners.len() == 1 { break }
 
         // If we just started or we got through all the scanners, then start over
-        let mut first = 0;
+        let first = 0;
 
         // while first < scanners.len() - 1 {
             let mut second = first + 1;
@@ -69,7 +71,7 @@ pub fn part1(mut scanners: Vec<Matrix>) -> (Matrix, HashMap<usize, Matrix>) {
                             // 2. Add the displacement to 0 matrix or existing displacement
                             let mut_in_place = tracker
                                 .entry(original_first) // Entry enum (Occupied or Vacant)
-                                .or_insert(HashMap::new()) // &mut HashMap<i32, Matrix>
+                                .or_default() // &mut HashMap<i32, Matrix>
                                     .entry(original_second) // Entry enum
                                     .or_insert(Matrix::from([[0,0,0]].as_slice())); // &mut Matrix
                             *mut_in_place = displacement.clone() + mut_in_place.clone();
@@ -83,7 +85,7 @@ pub fn part1(mut scanners: Vec<Matrix>) -> (Matrix, HashMap<usize, Matrix>) {
                                     .for_each(|(second_, disp)| {
                                         tracker
                                             .entry(original_first)
-                                            .or_insert(HashMap::new())
+                                            .or_default()
                                             .insert(second_, Orientations::new(&disp).nth(rotation).unwrap());
                                     })
                             }
@@ -114,8 +116,8 @@ pub fn part1(mut scanners: Vec<Matrix>) -> (Matrix, HashMap<usize, Matrix>) {
                             unordered_distances[first] = to_unordered_hashset(&distances[first]);
 
                             // Signal progress
-                            println!("Combined scanners {} and {}", first, second);
-                            println!("{} scanners remaining", scanners.len());
+                            common::debug!(1, "Combined scanners {} and {}", first, second);
+                            common::debug!(1, "{} scanners remaining", scanners.len());
 
                         } else {
                             todo!();
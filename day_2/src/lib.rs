@@ -0,0 +1,288 @@
+pub mod trajectory;
+
+use std::fmt::{Display, Formatter};
+use common::{Position, Solution};
+
+/// One line of the planned course
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Command::Forward(x) => write!(f, "forward {x}"),
+            Command::Down(x) => write!(f, "down {x}"),
+            Command::Up(x) => write!(f, "up {x}"),
+        }
+    }
+}
+
+/// What can go wrong reading the course
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Commands are `forward`, `down` or `up`
+    NotADirection { at: Position, found: String },
+    /// Every command is followed by how far to go
+    NotAnAmount { at: Position, found: String },
+    /// Anything after the amount
+    Trailing { at: Position, found: String },
+    /// The course takes the submarine further than an `i64` can say, at
+    /// this command's amount
+    TooFar { at: Position },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotADirection { at, found } => {
+                write!(f, "{at}: expected `forward`, `down` or `up`, found `{found}`")
+            }
+            ParseError::NotAnAmount { at, found } => write!(f, "{at}: expected an amount, found `{found}`"),
+            ParseError::Trailing { at, found } => write!(f, "{at}: expected the end of the line, found `{found}`"),
+            ParseError::TooFar { at } => write!(f, "{at}: expected a course that stays in range, found one going too far"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_course(input: &str) -> Result<Vec<Command>, ParseError> {
+    let mut commands = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let mut tokens = line.split_whitespace();
+        let Some(direction) = tokens.next() else { continue };
+        let command: fn(i64) -> Command = match direction {
+            "forward" => Command::Forward,
+            "down" => Command::Down,
+            "up" => Command::Up,
+            _ => {
+                return Err(ParseError::NotADirection {
+                    at: Position::of(i, line, direction),
+                    found: direction.to_string(),
+                })
+            }
+        };
+        let amount = tokens.next().unwrap_or(&line[line.len()..]);
+        let amount = amount.parse().map_err(|_| ParseError::NotAnAmount {
+            at: Position::of(i, line, amount),
+            found: if amount.is_empty() { "the end of the line" } else { amount }.to_string(),
+        })?;
+        if let Some(extra) = tokens.next() {
+            return Err(ParseError::Trailing { at: Position::of(i, line, extra), found: extra.to_string() });
+        }
+        commands.push(command(amount));
+    }
+    Ok(commands)
+}
+
+/// The submarine went further than an `i64` can keep track of on the
+/// `step`th command, counting from 1
+#[derive(Debug, Eq, PartialEq)]
+pub struct Overflow {
+    pub step: usize,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "the submarine goes too far to keep track of on command {}", self.step)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// A way of reading the course. The puzzle has two, but anything that can
+/// follow commands and say where the submarine ended up will do.
+pub trait Navigator {
+    /// `None` if the command takes the submarine out of range
+    fn apply(&mut self, command: Command) -> Option<()>;
+
+    fn horizontal(&self) -> i64;
+
+    fn depth(&self) -> i64;
+
+    /// How steeply the submarine is pointing, for navigators that aim
+    fn aim(&self) -> Option<i64> {
+        None
+    }
+
+    /// What the puzzle wants to know at the end, which doesn't fit in an
+    /// `i64` for every position that does
+    fn answer(&self) -> i128 {
+        self.horizontal() as i128 * self.depth() as i128
+    }
+}
+
+/// Follows every command of the course, in order
+pub fn navigate<N: Navigator>(mut navigator: N, course: &[Command]) -> Result<N, Overflow> {
+    for (i, &command) in course.iter().enumerate() {
+        navigator.apply(command).ok_or(Overflow { step: i + 1 })?;
+    }
+    Ok(navigator)
+}
+
+/// Part 1's reading, where down and up change the depth right away
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Direct {
+    pub horizontal: i64,
+    pub depth: i64,
+}
+
+impl Navigator for Direct {
+    fn apply(&mut self, command: Command) -> Option<()> {
+        match command {
+            Command::Forward(x) => self.horizontal = self.horizontal.checked_add(x)?,
+            Command::Down(x) => self.depth = self.depth.checked_add(x)?,
+            Command::Up(x) => self.depth = self.depth.checked_sub(x)?,
+        }
+        Some(())
+    }
+
+    fn horizontal(&self) -> i64 {
+        self.horizontal
+    }
+
+    fn depth(&self) -> i64 {
+        self.depth
+    }
+}
+
+/// Part 2's reading, where down and up only tilt the submarine and going
+/// forward is what changes the depth
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Aimed {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Navigator for Aimed {
+    fn apply(&mut self, command: Command) -> Option<()> {
+        match command {
+            Command::Forward(x) => {
+                self.horizontal = self.horizontal.checked_add(x)?;
+                self.depth = self.depth.checked_add(self.aim.checked_mul(x)?)?;
+            }
+            Command::Down(x) => self.aim = self.aim.checked_add(x)?,
+            Command::Up(x) => self.aim = self.aim.checked_sub(x)?,
+        }
+        Some(())
+    }
+
+    fn horizontal(&self) -> i64 {
+        self.horizontal
+    }
+
+    fn depth(&self) -> i64 {
+        self.depth
+    }
+
+    fn aim(&self) -> Option<i64> {
+        Some(self.aim)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+    type Error = ParseError;
+    type Answer1 = i128;
+    type Answer2 = i128;
+    const DAY: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    /// The course, as long as both parts can follow it to the end
+    fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+        let course = parse_course(input)?;
+        let overflow = navigate(Direct::default(), &course).err().or(navigate(Aimed::default(), &course).err());
+        if let Some(Overflow { step }) = overflow {
+            // The line of that command, skipping blank ones like parse_course
+            let (i, line) = input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()).nth(step - 1).unwrap();
+            let amount = line.split_whitespace().nth(1).unwrap();
+            return Err(ParseError::TooFar { at: Position::of(i, line, amount) });
+        }
+        Ok(course)
+    }
+
+    fn part1(course: &Vec<Command>) -> i128 {
+        navigate(Direct::default(), course).expect("parse checks the course stays in range").answer()
+    }
+
+    fn part2(course: &Vec<Command>) -> i128 {
+        navigate(Aimed::default(), course).expect("parse checks the course stays in range").answer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands() {
+        assert_eq!(
+            parse_course("forward 12\n\n  down 3 \nup 1234567890123").unwrap(),
+            vec![Command::Forward(12), Command::Down(3), Command::Up(1234567890123)]
+        );
+        assert_eq!(
+            parse_course("forward 1\nbackward 2"),
+            Err(ParseError::NotADirection { at: Position { line: 2, column: 1 }, found: "backward".to_string() })
+        );
+        assert_eq!(
+            parse_course("down"),
+            Err(ParseError::NotAnAmount { at: Position { line: 1, column: 5 }, found: "the end of the line".to_string() })
+        );
+        assert!(matches!(parse_course("up x"), Err(ParseError::NotAnAmount { .. })));
+        assert!(matches!(parse_course("up 1 2"), Err(ParseError::Trailing { .. })));
+    }
+
+    /// A reading the puzzle doesn't have, built on part 1's: the submarine
+    /// can't go any deeper than `floor`
+    #[derive(Default)]
+    struct Floored {
+        sub: Direct,
+        floor: i64,
+    }
+
+    impl Navigator for Floored {
+        fn apply(&mut self, command: Command) -> Option<()> {
+            self.sub.apply(command)?;
+            self.sub.depth = self.sub.depth.min(self.floor);
+            Some(())
+        }
+
+        fn horizontal(&self) -> i64 {
+            self.sub.horizontal
+        }
+
+        fn depth(&self) -> i64 {
+            self.sub.depth
+        }
+    }
+
+    #[test]
+    fn navigators() {
+        let course = Day2::parse(Day2::EXAMPLE.unwrap()).unwrap();
+        assert_eq!(navigate(Direct::default(), &course), Ok(Direct { horizontal: 15, depth: 10 }));
+        assert_eq!(navigate(Aimed::default(), &course), Ok(Aimed { horizontal: 15, depth: 60, aim: 10 }));
+        let floored = navigate(Floored { floor: 5, ..Default::default() }, &course).unwrap();
+        assert_eq!(floored.answer(), 15 * 5);
+    }
+
+    #[test]
+    fn far_courses() {
+        // Fine for part 1, but the depth part 2 works out is too deep
+        let course = parse_course("down 5000000000\nforward 5000000000").unwrap();
+        assert_eq!(navigate(Aimed::default(), &course), Err(Overflow { step: 2 }));
+        assert_eq!(
+            Day2::parse("down 5000000000\n\nforward 5000000000"),
+            Err(ParseError::TooFar { at: Position { line: 3, column: 9 } })
+        );
+        // The answer is too big for an i64 even when the position isn't
+        let course = Day2::parse("forward 5000000000\ndown 5000000000").unwrap();
+        assert_eq!(Day2::part1(&course), 25_000_000_000_000_000_000);
+    }
+}
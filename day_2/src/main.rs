@@ -1,63 +1,63 @@
-use std::fs;
+use std::process::ExitCode;
 
-fn main() {
-    let input = read_input("input.txt");
-    let instructions = input.trim().split('\n');
-    let solution1 = solve1(instructions.clone());
-    println!("Part 1: {}", solution1);
-    let solution2 = solve2(instructions);
-    println!("Part 2: {}", solution2);
-}
+use common::{Solution, Source};
+use day_2::{trajectory, Aimed, Day2, Direct};
 
-fn read_input(filename: &str) -> String {
-    let result = fs::read_to_string(filename).expect("Something went wrong reading the file");
-    result
-}
+const TRAJECTORY_USAGE: &str = "\
+Usage: day_2 --trajectory MODEL [PATH | - | --example] [--profile [WIDTHxHEIGHT]]
 
-fn solve1(instructions: std::str::Split<char>) -> String {
-    let mut v: i32 = 0;
-    let mut h: i32 = 0;
-    for s in instructions {
-        let len: usize = s.len().try_into().unwrap();
-        let dir: &str = &s[..len - 1];
-        // print!("{}",dir);
-        let digit: &str = &s[len - 1..];
-        match dir {
-            "forward " => h += digit.parse::<i32>().unwrap(),
-            "down " => v += digit.parse::<i32>().unwrap(),
-            "up " => v -= digit.parse::<i32>().unwrap(),
-            _ => print!("Error"),
-        }
+Prints where the submarine is after every command as CSV, reading the
+course with MODEL: `direct` (part 1) or `aimed` (part 2). --profile draws
+the depth along the way instead, 80x20 characters unless told otherwise.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.split_first() {
+        Some((flag, rest)) if flag == "--trajectory" => match print_trajectory(rest) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {e}");
+                ExitCode::FAILURE
+            }
+        },
+        _ => common::main::<Day2>(),
     }
-    (v * h).to_string()
 }
 
-fn solve2(instructions: std::str::Split<char>) -> String {
-    let mut v: i32 = 0;
-    let mut h: i32 = 0;
-    let mut aim: i32 = 0;
-    for s in instructions {
-        let len: usize = s.len().try_into().unwrap();
-        let dir: &str = &s[..len - 1];
-        let digit: &str = &s[len - 1..];
-        drop(len);
-
-        match dir {
-            "forward " => {
-                let d = digit.parse::<i32>().unwrap();
-                h += d;
-                v += aim * d;
+/// `day_2 --trajectory` shows the route instead of solving the puzzle
+fn print_trajectory(args: &[String]) -> Result<(), String> {
+    let usage = |e: String| format!("{e}\n\n{TRAJECTORY_USAGE}");
+    let (model, args) = args.split_first().ok_or(usage("--trajectory needs a model".to_string()))?;
+    let mut profile = None;
+    let source = Source::from_args(&mut args.iter().peekable(), |arg, args| {
+        if arg != "--profile" {
+            return Ok(false);
+        }
+        profile = Some((80, 20));
+        if let Some(size) = args.next_if(|size| size.starts_with(|c: char| c.is_ascii_digit())) {
+            profile = size
+                .split_once('x')
+                .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                .filter(|&(w, h)| w > 1 && h > 1);
+            if profile.is_none() {
+                return Err(format!("expected a size like 80x20 of at least 2x2, found `{size}`"));
             }
-            "down " => aim += digit.parse::<i32>().unwrap(),
-            "up " => aim -= digit.parse::<i32>().unwrap(),
-            _ => print!("Error"),
         }
+        Ok(true)
+    })
+    .map_err(usage)?;
+
+    let input = source.read(Day2::EXAMPLE)?;
+    let course = Day2::parse(&input).map_err(|e| format!("bad input in {source}: {e}"))?;
+    let points = match model.as_str() {
+        "direct" => trajectory::trajectory(Direct::default(), &course),
+        "aimed" => trajectory::trajectory(Aimed::default(), &course),
+        _ => return Err(usage(format!("expected `direct` or `aimed`, found `{model}`"))),
     }
-    (v * h).to_string()
+    .map_err(|e| e.to_string())?;
+    match profile {
+        Some((width, height)) => print!("{}", trajectory::depth_profile(&points, width, height)),
+        None => print!("{}", trajectory::to_csv(&points)),
+    }
+    Ok(())
 }
-
-// fn sum(arr: &[i32]) -> i32 {
-//     let mut s = 0;
-//     for n in arr { s += n; }
-//     s
-// }
@@ -0,0 +1,117 @@
+//! Where the submarine is after every command of the course, for plotting
+//! the route rather than just multiplying where it ends up.
+use crate::{Command, Navigator, Overflow};
+
+/// Where the submarine is after `step` commands
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Point {
+    pub step: usize,
+    pub horizontal: i64,
+    pub depth: i64,
+    /// `None` for a navigator that doesn't aim
+    pub aim: Option<i64>,
+}
+
+impl Point {
+    fn of(step: usize, navigator: &impl Navigator) -> Self {
+        Point { step, horizontal: navigator.horizontal(), depth: navigator.depth(), aim: navigator.aim() }
+    }
+}
+
+/// Every point of the route, starting with step 0 before any command
+pub fn trajectory<N: Navigator>(mut navigator: N, course: &[Command]) -> Result<Vec<Point>, Overflow> {
+    let mut points = vec![Point::of(0, &navigator)];
+    for (i, &command) in course.iter().enumerate() {
+        navigator.apply(command).ok_or(Overflow { step: i + 1 })?;
+        points.push(Point::of(i + 1, &navigator));
+    }
+    Ok(points)
+}
+
+pub const HEADER: &str = "step,horizontal,depth,aim";
+
+/// The route as CSV, leaving the aim empty where there is none
+pub fn to_csv(points: &[Point]) -> String {
+    let mut csv = format!("{HEADER}\n");
+    for p in points {
+        let aim = p.aim.map(|aim| aim.to_string()).unwrap_or_default();
+        csv.push_str(&format!("{},{},{},{aim}\n", p.step, p.horizontal, p.depth));
+    }
+    csv
+}
+
+/// The route drawn from the side, `width` characters across and `height`
+/// down, with the surface (depth 0) as a line of `~` where it fits and
+/// the deepest depth at the bottom
+pub fn depth_profile(points: &[Point], width: usize, height: usize) -> String {
+    assert!(width > 1 && height > 1, "a profile needs at least 2x2 characters");
+    let bounds = |f: fn(&Point) -> i64| {
+        let values = points.iter().map(f);
+        (values.clone().min().unwrap_or(0).min(0), values.max().unwrap_or(0).max(0))
+    };
+    let (left, right) = bounds(|p| p.horizontal);
+    let (top, bottom) = bounds(|p| p.depth);
+    // Where a horizontal position or depth lands, from 0 to `cells - 1`
+    let scale = |value: i64, low: i64, high: i64, cells: usize| {
+        let (value, low, high) = (value as i128, low as i128, high as i128);
+        if high == low { 0 } else { ((value - low) * (cells - 1) as i128 / (high - low)) as usize }
+    };
+
+    let mut canvas = vec![vec![' '; width]; height];
+    canvas[scale(0, top, bottom, height)].fill('~');
+    let cells: Vec<(usize, usize)> = points
+        .iter()
+        .map(|p| (scale(p.horizontal, left, right, width), scale(p.depth, top, bottom, height)))
+        .collect();
+    // Joining each point to the next, so the route reads as one line
+    for pair in cells.windows(2) {
+        let ((c0, r0), (c1, r1)) = (pair[0], pair[1]);
+        let steps = c0.abs_diff(c1).max(r0.abs_diff(r1)).max(1);
+        for s in 0..=steps {
+            let between = |a: usize, b: usize| (a as i64 + (b as i64 - a as i64) * s as i64 / steps as i64) as usize;
+            canvas[between(r0, r1)][between(c0, c1)] = '#';
+        }
+    }
+    if let [(c, r)] = cells[..] {
+        canvas[r][c] = '#';
+    }
+
+    let label = |row: usize| match row {
+        0 => top.to_string(),
+        r if r == height - 1 => bottom.to_string(),
+        _ => String::new(),
+    };
+    let label_width = label(0).len().max(label(height - 1).len());
+    let mut profile = String::new();
+    for (row, cells) in canvas.iter().enumerate() {
+        let line: String = cells.iter().collect();
+        profile.push_str(&format!("{:>label_width$} |{}\n", label(row), line.trim_end()));
+    }
+    profile
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Aimed, Day2, Direct};
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let course = Day2::parse(Day2::EXAMPLE.unwrap()).unwrap();
+        let points = trajectory(Aimed::default(), &course).unwrap();
+        assert_eq!(points.len(), 7);
+        assert_eq!(points[6], Point { step: 6, horizontal: 15, depth: 60, aim: Some(10) });
+        assert_eq!(
+            to_csv(&points),
+            "step,horizontal,depth,aim\n0,0,0,0\n1,5,0,0\n2,5,0,5\n3,13,40,5\n4,13,40,2\n5,13,40,10\n6,15,60,10\n"
+        );
+        let csv = to_csv(&trajectory(Direct::default(), &course).unwrap());
+        assert!(csv.ends_with("\n6,15,10,\n"));
+
+        assert_eq!(
+            depth_profile(&points, 16, 4),
+            " 0 |#########~~~~~~~\n   |         ####\n   |             ##\n60 |               #\n"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+
+use std::fmt::{Display, Formatter};
+
+use common::{Position, Solution};
+use grid::{Cell, Grid};
+
+pub type Algorithm = Vec<bool>;
+pub type Image = Grid<bool>;
+
+/// What can go wrong reading the algorithm and the image
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The algorithm is the first line and has a pixel for every 9 bit index
+    BadAlgorithm { found: usize },
+    /// Pixels are either light `#` or dark `.`
+    NotAPixel { at: Position, found: char },
+    /// The image after the blank line is not a rectangle of pixels
+    Image(grid::ParseError),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::BadAlgorithm { found } => {
+                write!(f, "line 1: expected an algorithm of 512 pixels, found {found}")
+            }
+            ParseError::NotAPixel { at, found } => {
+                write!(f, "{at}: expected `#` or `.`, found `{found}`")
+            }
+            ParseError::Image(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (Algorithm, Image);
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 20;
+
+    fn parse(raw: &str) -> Result<(Algorithm, Image), ParseError> {
+        parse_input(raw)
+    }
+
+    fn part1((algo, image): &(Algorithm, Image)) -> usize {
+        part1(algo, image.clone())
+    }
+
+    fn part2((algo, image): &(Algorithm, Image)) -> usize {
+        part2(algo, image.clone())
+    }
+}
+
+fn part1(algo: &Algorithm, image: Image) -> usize {
+    let mut background = false;
+    let image = enhance_image(algo, image, background);
+    background = true;
+    let image = enhance_image(algo, image, background);
+    image.iter().map(|&b| b as usize).sum()
+}
+
+fn part2(algo: &Algorithm, mut image: Image) -> usize {
+    let mut background = false;
+    for _ in 0..50 {
+        image = enhance_image(algo, image, background);
+        background = !background;
+    }
+    image.iter().map(|&b| b as usize).sum()
+}
+
+fn bools_to_u16(image: &Image, coords: (usize, usize)) -> u16 {
+    let mut n = 0;
+    let mut places = 8;
+
+    for i in 0..3 {
+        for j in 0..3 {
+            n |= (image[(coords.0 + i, coords.1 + j)] as u16) << places;
+            places -= 1;
+        }
+    }
+
+    n
+}
+
+fn enhance_image(algo: &Algorithm, image: Image, background: bool) -> Image {
+    let new_dims = (image.height() + 2, image.width() + 2);
+    let padded = pad_image(image, background);
+    Grid::from_fn(new_dims.1, new_dims.0, |coords| {
+        let algo_index = bools_to_u16(&padded, coords);
+        enhance_pixel(algo_index, algo) // pfft
+    })
+}
+
+fn enhance_pixel(n: u16, algo: &Algorithm) -> bool {
+    algo[n as usize]
+}
+
+// My algorithm starts with a light pixel, so my infinitely sized background
+// will alternate between light and dark pixels
+fn pad_image(image: Image, background: bool) -> Image {
+    let new_dims = (image.height() + 4, image.width() + 4); // rows and columns
+
+    // Two rows and columns of background on every side
+    Grid::from_fn(new_dims.1, new_dims.0, |(row, col)| {
+        row.checked_sub(2)
+            .zip(col.checked_sub(2))
+            .and_then(|coords| image.get(coords))
+            .copied()
+            .unwrap_or(background)
+    })
+}
+
+#[cfg(test)]
+fn read_input(path: &str) -> (Algorithm, Image) {
+    parse_input(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn parse_input(raw: &str) -> Result<(Algorithm, Image), ParseError> {
+    let mut lines = raw.trim_end().lines().enumerate();
+    let algo: Algorithm = match lines.next() {
+        Some((i, line)) => text_to_bools(i, line)?,
+        None => Vec::new(),
+    };
+    if algo.len() != 512 {
+        return Err(ParseError::BadAlgorithm { found: algo.len() });
+    }
+    lines.next();
+
+    let image = Grid::from_lines(lines, bool::EXPECTED, bool::from_char)
+        .map_err(ParseError::Image)?;
+
+    Ok((algo, image))
+}
+
+fn text_to_bools(index: usize, line: &str) -> Result<Vec<bool>, ParseError> {
+    line.chars()
+        .enumerate()
+        .map(|(j, c)| bool::from_char(c).ok_or(ParseError::NotAPixel {
+            at: Position { line: index + 1, column: j + 1 },
+            found: c,
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "needs the puzzle input, which is not checked in"]
+    fn test_input() {
+        let (algo, image) = read_input("input.txt");
+        assert_eq!(algo.len(), 512);
+        assert_eq!(image.width(), 100);
+        assert_eq!(image.height(), 100);
+    }
+
+    #[test]
+    fn test_padding() {
+        let image1: Image = vec![vec![true]].into();
+        let image2: Image = vec![vec![false]].into();
+
+        let expected1 = vec![vec![false, false, false, false, false],
+                             vec![false, false, false, false, false],
+                             vec![false, false, true,  false, false],
+                             vec![false, false, false, false, false],
+                             vec![false, false, false, false, false]];
+        let expected2 = vec![vec![true, true,  true, true, true],
+                             vec![true, true,  true, true, true],
+                             vec![true, true, false, true, true],
+                             vec![true, true,  true, true, true],
+                             vec![true, true,  true, true, true]];
+
+        assert_eq!(pad_image(image1, false), Image::from(expected1));
+        assert_eq!(pad_image(image2, true), Image::from(expected2));
+    }
+
+    #[test]
+    fn test_to_u16() {
+        let image = Image::from(vec![vec![false, false, false],
+                                     vec![true,  false, false],
+                                     vec![true,  false, true]]);
+        let actual = bools_to_u16(&image, (0, 0));
+        assert_eq!(actual, 37);
+    }
+
+    #[test]
+    fn test_bad_pixel() {
+        let raw = format!("{}\n\n#..\n.#.\n.?#\n", "#.".repeat(256));
+        assert_eq!(
+            parse_input(&raw).unwrap_err(),
+            ParseError::Image(grid::ParseError::NotACell {
+                at: Position { line: 5, column: 2 },
+                expected: "`#` or `.`",
+                found: '?',
+            }),
+        );
+
+        let raw = format!("{}\n\n#..\n.#\n", "#.".repeat(256));
+        assert_eq!(
+            parse_input(&raw).unwrap_err(),
+            ParseError::Image(grid::ParseError::Ragged { line: 4, expected: 3, found: 2 }),
+        );
+    }
+}
@@ -1,3 +1,6 @@
+use std::{collections::HashMap, fmt::{Display, Formatter}};
+use common::{Position, Solution};
+
 #[derive(Clone, Debug, Default, Hash, Eq, PartialEq)]
 pub struct Player {
     pub position: usize,
@@ -36,3 +39,208 @@ impl DeterministicDie {
         result
     }
 }
+
+// ([p1 position, p2 position, p1 score, p2 score, winner], n_universes)
+// winner = {0: no one, 1: p1, 2: p2}
+type Universes = HashMap<([Player; 2], usize), usize>;
+
+/// What can go wrong reading the starting positions
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Something other than what the player's line starts with
+    Expected { at: Position, expected: &'static str, found: String },
+    /// The track only goes from 1 to 10
+    NotAPosition { at: Position, found: String },
+    /// The input ends before the player on `line`
+    MissingPlayer { line: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::Expected { at, expected, found } => {
+                write!(f, "{at}: expected {expected}, found `{found}`")
+            }
+            ParseError::NotAPosition { at, found } => {
+                write!(f, "{at}: expected a position from 1 to 10, found `{found}`")
+            }
+            ParseError::MissingPlayer { line } => {
+                write!(f, "line {line}: expected player {line}'s starting position, found the end of the input")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = [Player; 2];
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 21;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<[Player; 2], ParseError> {
+        let mut lines = input.lines();
+        let mut player = |i| lines.next().ok_or(ParseError::MissingPlayer { line: i + 1 }).and_then(|line| parse_player(i, line));
+        Ok([player(0)?, player(1)?])
+    }
+
+    fn part1(players: &[Player; 2]) -> usize {
+        part1(players.clone())
+    }
+
+    fn part2(players: &[Player; 2]) -> usize {
+        let universes = HashMap::from([
+            ((players.clone(), 0), 1)
+        ]);
+
+        part2(universes)
+    }
+}
+
+// "Player 1 starting position: 10", on the `index`th line
+fn parse_player(index: usize, line: &str) -> Result<Player, ParseError> {
+    let prefix = ["Player 1 starting position: ", "Player 2 starting position: "][index];
+    let found = line.strip_prefix(prefix).ok_or(ParseError::Expected {
+        at: Position::of(index, line, line),
+        expected: ["`Player 1 starting position: `", "`Player 2 starting position: `"][index],
+        found: line.to_string(),
+    })?;
+    let position: usize = found
+        .trim()
+        .parse()
+        .ok()
+        .filter(|position| (1..=10).contains(position))
+        .ok_or(ParseError::NotAPosition { at: Position::of(index, line, found), found: found.to_string() })?;
+    Ok(Player::new(position - 1)) // I'm subtracting 1 from the positions
+}
+
+fn part1(mut players: [Player; 2]) -> usize {
+    const WIN_CONDITION: usize = 1000;
+
+    let mut die = DeterministicDie::default();
+    let mut player_turn = 0;
+
+    while (players[0].score < WIN_CONDITION) & (players[1].score < WIN_CONDITION) {
+        let movement = (0..3).map(|_| die.roll()).sum::<usize>();
+        players[player_turn].advance(movement);
+        player_turn += 1;
+        player_turn %= 2;
+    }
+
+    let lower_score = players.iter().map(|player| player.score).min().unwrap();
+    let n_rolls = die.n_rolls;
+    lower_score * n_rolls
+}
+
+fn part2(mut universes: Universes) -> usize {
+
+    const MULTIPLIERS: [usize; 7] = [1, 3, 6, 7, 6, 3, 1];
+    const MOVEMENT: [usize; 7] = [3, 4, 5, 6, 7, 8, 9];
+
+    let mut player_turn = 0;
+
+    let wins = loop {
+        if universes // If there are no more universes where no one has won
+            .keys()
+            .map(|state| state.1)
+            .min()
+            .unwrap() > 0
+        { // Count up the number of wins
+            // [p1 wins, p2 wins]
+            let mut wins = [0; 2];
+            universes.into_iter().for_each(|(state, n)| {
+                wins[state.1-1] += n;
+            });
+            break wins
+        }
+        
+        // Updating all the universes could be done more efficiently with a
+        // binary heap but this is fine for this problem
+        let mut new_universes = HashMap::with_capacity(universes.len()*7);
+        for ((players, winner), n) in universes {
+            if winner == 0 {
+                for (movement, multiplier) in MOVEMENT.into_iter().zip(MULTIPLIERS) {
+                    let mut new_players = players.clone();
+                    new_players[player_turn].advance(movement);
+
+                    // Record if they win
+                    let winner = if new_players[player_turn].score > 20 {
+                        player_turn + 1
+                    } else {
+                        0
+                    };
+
+                    *new_universes.entry((new_players, winner)).or_insert(0) += n * multiplier;
+                }
+            } else {
+                *new_universes.entry((players, winner)).or_insert(0) += n;
+            }
+        }
+
+        // Update the current universes with the new universes
+        universes = new_universes;
+        player_turn = (player_turn + 1) % 2;
+    };
+
+    // Return the higher number of wins
+    wins.into_iter().max().unwrap()
+}
+
+// Rolls:
+// 1 + 1 + 1 = 3
+// 1 + 1 + 2 = 4 with three outcomes
+// 1 + 1 + 3 = 1 + 2 + 2 = 5 with six (3 + 3) outcomes
+// 1 + 2 + 3 = 2 + 2 + 2 = 6 with seven (6 + 1) outcomes
+// 1 + 3 + 3 = 2 + 2 + 3 = 7 with six (3 + 3) outcomes
+// 2 + 3 + 3 = 8 with three outcomes
+// 3 + 3 + 3 = 9
+//
+// 1 + 3 + 6 + 7 + 6 + 3 + 1 = 27 different outcomes from 3 to 9 which checks out
+//
+// I only have to track 7 different outcomes and the likelihood of each
+// The longest possible game shouldn't go any longer than 14 rounds (28 turns)
+// (3^3)^28 total possible universes
+//
+// The hardest part is going to be keeping track of score and which games have
+// been terminated.
+//
+// We can shrink the space to four dimensions:
+// - Player 1's score
+// - Player 1's position
+// - Player 2's score
+// - Player 2's position
+//
+// Each player's score only has 31 possible values: 0 -> 20 + 10 = 30
+// Each player's position has only 10 possible values: 1 -> 10
+// That means our space only contains 31 * 10 * 31 * 10 = 96100 unique universes
+//
+// Can I use a transition matrix?
+//
+// No, I will use a HashMap and repeatedly move the whole thing to a new HashMap
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_positions() {
+        assert_eq!(
+            Day21::parse("Player 1 starting position: 4\nPlayer 3 starting position: 8").err(),
+            Some(ParseError::Expected {
+                at: Position { line: 2, column: 1 },
+                expected: "`Player 2 starting position: `",
+                found: "Player 3 starting position: 8".to_string(),
+            })
+        );
+        assert_eq!(
+            Day21::parse("Player 1 starting position: 11\nPlayer 2 starting position: 8").err(),
+            Some(ParseError::NotAPosition { at: Position { line: 1, column: 29 }, found: "11".to_string() })
+        );
+        assert_eq!(Day21::parse("Player 1 starting position: 4").err(), Some(ParseError::MissingPlayer { line: 2 }));
+    }
+}
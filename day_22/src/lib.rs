@@ -0,0 +1,182 @@
+use std::{ops::Range, cmp::{max, min}, fmt::{Display, Formatter}};
+use common::{Position, Solution};
+
+/// What can go wrong reading the reboot steps
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Something other than what the step needs at that point
+    Expected { at: Position, expected: &'static str, found: String },
+    /// A range like `x=12..10` that ends before it starts
+    Backwards { at: Position, found: String },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::Expected { at, expected, found } => {
+                write!(f, "{at}: expected {expected}, found `{found}`")
+            }
+            ParseError::Backwards { at, found } => {
+                write!(f, "{at}: expected a range that ends after it starts, found `{found}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Clone, Debug)]
+pub struct Cuboid {
+    x_range: Range<i64>,
+    y_range: Range<i64>,
+    z_range: Range<i64>,
+    pub positive: bool
+}
+
+impl Cuboid {
+
+    /// `None` if any of the ranges ends before it starts, like the
+    /// intersection of two cuboids that don't meet
+    fn new([x_range, y_range, z_range]: [Range<i64>; 3], positive: bool) -> Option<Cuboid> {
+        [&x_range, &y_range, &z_range]
+            .iter()
+            .all(|range| range.start <= range.end)
+            .then_some(Cuboid { x_range, y_range, z_range, positive })
+    }
+
+    fn volume(&self) -> i64 {
+        let x = self.x_range.end - self.x_range.start + 1;
+        let y = self.y_range.end - self.y_range.start + 1;
+        let z = self.z_range.end - self.z_range.start + 1;
+        x * y * z * (if self.positive { 1 } else { -1 })
+    }
+
+    fn intersection(&self, rhs: &Cuboid) -> Option<Cuboid> { // The right-hand side will be the ones that are already in the queue
+        let x_range = Range { 
+            start: max(self.x_range.start, rhs.x_range.start),
+            end:   min(self.x_range.end,   rhs.x_range.end  ),
+        };
+        let y_range = Range { 
+            start: max(self.y_range.start, rhs.y_range.start),
+            end:   min(self.y_range.end,   rhs.y_range.end  ),
+        };
+        let z_range = Range { 
+            start: max(self.z_range.start, rhs.z_range.start),
+            end:   min(self.z_range.end,   rhs.z_range.end  ),
+        };
+
+        Cuboid::new([x_range, y_range, z_range], !rhs.positive)
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Cuboid>;
+    type Error = ParseError;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    const DAY: u8 = 22;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Vec<Cuboid>, ParseError> {
+        read_input(input)
+    }
+
+    fn part1(bootup: &Vec<Cuboid>) -> i64 {
+        part1(bootup)
+    }
+
+    fn part2(bootup: &Vec<Cuboid>) -> i64 {
+        part2(bootup)
+    }
+}
+
+fn part1(bootup: &[Cuboid]) -> i64 {
+
+    let mut blockchain = build_blockchain(bootup);
+    let blocks_on = blockchain.iter().map(|cuboid| cuboid.volume()).sum::<i64>();
+
+    let interior = Cuboid {
+        x_range: Range { start: -50, end: 50 },
+        y_range: Range { start: -50, end: 50 },
+        z_range: Range { start: -50, end: 50 },
+        positive: false,
+    };
+
+    add_cuboid(&mut blockchain, &interior);
+    let blocks_on_wo_interior = blockchain.iter().map(|cuboid| cuboid.volume()).sum::<i64>();
+
+    blocks_on - blocks_on_wo_interior
+}
+
+fn part2(bootup: &[Cuboid]) -> i64 {
+    let blockchain = build_blockchain(bootup);
+    blockchain.iter().map(|cuboid| cuboid.volume()).sum::<i64>()
+}
+
+fn build_blockchain(bootup: &[Cuboid]) -> Vec<Cuboid> {
+    let mut blockchain = Vec::with_capacity(1000);
+    bootup.iter().for_each(|cuboid| add_cuboid(&mut blockchain, cuboid));
+    blockchain
+}
+
+fn add_cuboid(blockchain: &mut Vec<Cuboid>, cuboid: &Cuboid) {
+
+    let mut new_layers = blockchain.iter().filter_map(|layer| {
+        cuboid.intersection(layer)
+    })
+    .collect::<Vec<_>>();
+
+    if cuboid.positive { new_layers.push(cuboid.clone()); }
+
+    blockchain.append(&mut new_layers);
+}
+
+
+fn read_input(s: &str) -> Result<Vec<Cuboid>, ParseError> {
+
+    let mut cuboids = Vec::with_capacity(420);
+
+    for (i, line) in s.trim_end().lines().enumerate() {
+        let expected = |token: &str, expected| ParseError::Expected {
+            at: Position::of(i, line, token),
+            found: token.to_string(),
+            expected,
+        };
+
+        // on x=10..12,y=10..12,z=10..12
+        let (on_off, ranges) = line.split_once(' ').unwrap_or((line, &line[line.len()..]));
+        let on_off = match on_off {
+            "on" => true,
+            "off" => false,
+            _ => return Err(expected(on_off, "`on` or `off`")),
+        };
+
+        let mut axes = ranges.split(',');
+        let mut range = |axis| {
+            let text = axes.next().unwrap_or(&ranges[ranges.len()..]);
+            let range = text
+                .strip_prefix(axis)
+                .and_then(|range| range.split_once(".."))
+                .ok_or_else(|| expected(text, "a range like `x=10..12`"))?;
+            let number = |n: &str| n.parse::<i64>().map_err(|_| expected(n, "a number"));
+            let (start, end) = (number(range.0)?, number(range.1)?);
+            if start > end {
+                return Err(ParseError::Backwards {
+                    at: Position::of(i, line, text),
+                    found: text.to_string(),
+                });
+            }
+            Ok(Range { start, end })
+        };
+        let (x_range, y_range, z_range) = (range("x=")?, range("y=")?, range("z=")?);
+        if let Some(extra) = axes.next() {
+            return Err(expected(extra, "the end of the line"));
+        }
+
+        cuboids.push(Cuboid { x_range, y_range, z_range, positive: on_off });
+    }
+
+    Ok(cuboids)
+}
@@ -1,26 +1,22 @@
 use std::{
     cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd},
-    collections::HashMap,
+    collections::{BinaryHeap, HashMap},
     fmt::{Debug, Display, Error, Formatter},
     hash::{Hash, Hasher},
     str::FromStr
 };
 
+use common::{Position, Solution};
 use lazy_static::lazy_static;
 
-// y, x (d_row, d_col)
-const MOVEMENT: [(i32, i32); 10] = [
-    (-1, 0), (1, 0), (0, -1), (0, 1),
-    (-1, -1), (-1, 1), (1, -1), (1, 1),
-    (0, -2), (0, 2)
-];
-
 const EMPTY: i32 = 0;
 const A: i32 = 1;
 const B: i32 = 10;
 const C: i32 = 100;
 const D: i32 = 1000;
 pub const SIZES: [i32; 4] = [A, B, C, D];
+// The two rows that get unfolded into the middle of the rooms for part 2
+const FOLDED: [i32; 8] = [D, C, B, A, D, B, A, C];
 const IND_TO_COORD: [(i32, i32); 23] = [
     (1, 1), (1, 2), (1, 4), (1, 6), (1, 8), (1, 10), (1, 11),
     (2, 3), (2, 5), (2, 7), (2, 9),
@@ -114,52 +110,76 @@ impl<const N: usize> Board<N> {
         })
     }
 
+    /// Every board one move away. An amphipod moves out of its room to a
+    /// spot in the hallway, or from the hallway all the way down into its
+    /// own room once that only holds its own kind. Moving one square at a
+    /// time instead would let amphipods stop in the hallway and set off
+    /// again, which they can't.
     pub fn next_boards(&self) -> Vec<Self> {
         let mut nexts = Vec::new();
-
-        for (i, &amph) in self.inner.iter().enumerate() {
-
-            if amph == EMPTY { continue }
-
-            let (row, col) = IND_TO_COORD[i];
-
-            for &(y, x) in MOVEMENT.iter() {
-                let new_row = row + y;
-                let new_col = col + x;
-
-                // Does this where I am trying to move even exist?
-                let Some(&new_ind) = COORD_TO_IND.get(&(new_row, new_col)) else { continue };
-                if new_ind >= N { continue }
-
-                let proper_col = (2 * SIZES.iter().position(|&size| size == amph).unwrap() + 3) as i32;
-
-                if self.inner[new_ind] == EMPTY && // the new place is empty
-                    match y {
-                        0 => !(x.abs() == 2 && new_row != 1), // if we are moving sideways, we cannot be in a room and moving 2 at the same time
-                        -1 => !(col == proper_col && self.is_room_ready(proper_col)), // if we are moving up, we must not already be in the correct room that is ready
-                        1 => new_col == proper_col && self.is_room_ready(proper_col), // if we are moving down, it must be the correct room that is ready
-                        _ => unreachable!(),
-                    }
-                {
-                    let mut new_inner = self.inner;
-                    // Swap the amphipod into the new spot
-                    new_inner.swap(i, new_ind);
-                    // Calculate the energy expended by the travel
-                    let energy: i32 = (x.abs() + y.abs()) * amph + self.energy();
-                    // Calculate the change in loss
-                    let old_dist = Self::amph_displacement(amph, (row, col));
-                    let new_dist = Self::amph_displacement(amph, (new_row, new_col));
-                    let change_in_loss = (new_dist - old_dist) * amph;
-                    
-                    nexts.push(Board::with_loss(new_inner, energy, self.loss() + change_in_loss));
+        let hallway_col = |h: usize| IND_TO_COORD[h].1;
+        let clear = |from: i32, to: i32| {
+            (0..7).all(|h| {
+                let col = hallway_col(h);
+                self.inner[h] == EMPTY || col < from.min(to) || col > from.max(to)
+            })
+        };
+        let mut push = |from: usize, to: usize, distance: i32| {
+            let amph = self.inner[from];
+            let mut new_inner = self.inner;
+            new_inner.swap(from, to);
+            let change_in_loss = (Self::amph_displacement(amph, IND_TO_COORD[to])
+                - Self::amph_displacement(amph, IND_TO_COORD[from]))
+                * amph;
+            nexts.push(Board::with_loss(new_inner, self.energy + distance * amph, self.loss + change_in_loss));
+        };
+
+        // Out of a room, for the top amphipod of a room that isn't done
+        for room in 0..4 {
+            let col = 3 + 2 * room as i32;
+            if self.is_room_ready(col) {
+                continue;
+            }
+            let Some(depth) = (0..Self::ROWS as usize).find(|&d| self.inner[7 + 4 * d + room] != EMPTY) else { continue };
+            let from = 7 + 4 * depth + room;
+            for h in 0..7 {
+                if clear(col, hallway_col(h)) {
+                    push(from, h, depth as i32 + 1 + (hallway_col(h) - col).abs());
                 }
             }
         }
 
+        // Into its own room, as deep as it goes
+        for h in 0..7 {
+            let amph = self.inner[h];
+            if amph == EMPTY {
+                continue;
+            }
+            let room = SIZES.iter().position(|&size| size == amph).unwrap();
+            let col = 3 + 2 * room as i32;
+            let (here, passing) = (hallway_col(h), hallway_col(h) + (col - hallway_col(h)).signum());
+            if !self.is_room_ready(col) || !clear(passing, col) {
+                continue;
+            }
+            let Some(depth) = (0..Self::ROWS as usize).rev().find(|&d| self.inner[7 + 4 * d + room] == EMPTY) else { continue };
+            push(h, 7 + 4 * depth + room, depth as i32 + 1 + (here - col).abs());
+        }
+
         nexts
     }
 }
 
+impl Board<15> {
+    /// Unfolds the diagram into the full-sized one used in part 2
+    pub fn unfold(&self) -> Board<23> {
+        let mut inner = [EMPTY; 23];
+        inner[..11].copy_from_slice(&self.inner[..11]);
+        inner[11..19].copy_from_slice(&FOLDED);
+        inner[19..].copy_from_slice(&self.inner[11..]);
+        Board::new(inner, self.energy)
+    }
+}
+
 impl<const N: usize> Debug for Board<N> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
         for row in 0..=Self::ROWS+1 {
@@ -228,26 +248,71 @@ impl<const N: usize> Display for Board<N> {
 
 impl<const N: usize> Eq for Board<N> { }
 
+/// What can go wrong reading a burrow
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Spots are `.` or an amphipod from `A` to `D`, with `#` walls and
+    /// whitespace in between
+    Unexpected { at: Position, found: char },
+    /// There are more spots than the burrow has
+    TooManySpots { at: Position, expected: usize },
+    /// The input runs out before every spot in the burrow is filled in
+    TooFewSpots { expected: usize, found: usize },
+    /// Every room has to fill up with its own kind of amphipod
+    WrongCount { amphipod: char, expected: usize, found: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match self {
+            ParseError::Unexpected { at, found } => {
+                write!(f, "{at}: expected `.` or an amphipod from `A` to `D`, found `{found}`")
+            }
+            ParseError::TooManySpots { at, expected } => {
+                write!(f, "{at}: expected a burrow of {expected} spots, found more")
+            }
+            ParseError::TooFewSpots { expected, found } => {
+                write!(f, "expected a burrow of {expected} spots, found {found}")
+            }
+            ParseError::WrongCount { amphipod, expected, found } => {
+                write!(f, "expected {expected} amphipods of type {amphipod}, found {found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 impl<const N: usize> FromStr for Board<N> {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut inner = [EMPTY; N];
         let mut i = 0;
 
-        for c in s.chars() {
-            inner[i] = match c {
-                '.' => EMPTY,
-                'A' => A,
-                'B' => B,
-                'C' => C,
-                'D' => D,
-                _   => continue,
-            };
-
-            i += 1;
+        for (line_index, line) in s.lines().enumerate() {
+            for (j, c) in line.char_indices() {
+                let amph = match c {
+                    '.' => EMPTY,
+                    'A' => A,
+                    'B' => B,
+                    'C' => C,
+                    'D' => D,
+                    '#' => continue,
+                    _ if c.is_whitespace() => continue,
+                    _ => return Err(ParseError::Unexpected { at: Position::of(line_index, line, &line[j..]), found: c }),
+                };
+                let spot = inner
+                    .get_mut(i)
+                    .ok_or(ParseError::TooManySpots { at: Position::of(line_index, line, &line[j..]), expected: N })?;
+                *spot = amph;
+
+                i += 1;
+            }
+        }
+        if i < N {
+            return Err(ParseError::TooFewSpots { expected: N, found: i });
         }
-        assert_eq!(i, N);
 
         Ok(Self::new(inner, 0))
     }
@@ -295,15 +360,76 @@ impl<const N: usize> PartialEq for Board<N> {
 
 impl<const N: usize> PartialOrd for Board<N> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let o = self.cmp(other);
-        if o == Ordering::Equal {
-            None
-        } else {
-            Some(o)
+        Some(self.cmp(other))
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Board<15>;
+    type Error = ParseError;
+    type Answer1 = i32;
+    type Answer2 = i32;
+    const DAY: u8 = 23;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Board<15>, ParseError> {
+        let board = Board::<15>::from_str(input)?;
+        for (amphipod, size) in ['A', 'B', 'C', 'D'].into_iter().zip(SIZES) {
+            let found = board.inner.iter().filter(|&&amph| amph == size).count();
+            if found != 2 {
+                return Err(ParseError::WrongCount { amphipod, expected: 2, found });
+            }
         }
+        Ok(board)
+    }
+
+    fn part1(board1: &Board<15>) -> i32 {
+        common::debug!(2, "{:?}", board1);
+        a_star(board1.clone())
+    }
+
+    fn part2(board1: &Board<15>) -> i32 {
+        let board2 = board1.unfold();
+        common::debug!(2, "{:?}", &board2);
+        a_star(board2)
     }
 }
 
+pub fn a_star<const N: usize>(board: Board<N>) -> i32 {
+    let mut goal = board.clone();
+    // I think I have to do a little dijkstra's
+    let mut explored = HashMap::from([(board.clone(), board.energy())]);
+    let mut queue = BinaryHeap::from([board]);
+    let mut count = 0;
+
+    while let Some(board) = queue.pop() {
+        if board.loss() < goal.loss() {
+            goal = board.clone();
+        }
+        if goal.loss() == 0 {
+            common::debug!(2, "{:?}", &goal);
+            return goal.energy()
+        }
+
+        let nexts: Vec<_> = board
+            .next_boards()
+            .into_iter()
+            .filter(|next| explored.get(next).unwrap_or(&i32::MAX) > &next.energy())
+            .collect();
+        nexts.iter().for_each(|next| { explored.insert(next.clone(), next.energy()); });
+        queue.extend(nexts);
+
+        count += 1;
+        if count % 100000 == 0 {
+            common::debug!(1, "{count} boards explored");
+        }
+    }
+
+    0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,7 +450,7 @@ mod tests {
           A B C D
           A B C D
         "#;
-        let board = Board::<15>::from_str(s).unwrap();
+        let _board = Board::<15>::from_str(s).unwrap();
     }
 
     #[test]
@@ -362,10 +488,34 @@ mod tests {
         assert_eq!(board.loss(), 0);
     }
 
+    #[test]
+    fn example() {
+        let board = Day23::parse(Day23::EXAMPLE.unwrap()).unwrap();
+        assert_eq!(Day23::part1(&board), 12521);
+        assert_eq!(Day23::part2(&board), 44169);
+    }
+
+    #[test]
+    fn bad_burrows() {
+        assert_eq!(
+            Day23::parse(".. . . . ..\n  B C E D\n  A D C A").err(),
+            Some(ParseError::Unexpected { at: Position { line: 2, column: 7 }, found: 'E' })
+        );
+        assert_eq!(
+            Day23::parse(".. . . . ..\n  B C B D\n  A D C A\n  A").err(),
+            Some(ParseError::TooManySpots { at: Position { line: 4, column: 3 }, expected: 15 })
+        );
+        assert_eq!(Day23::parse("").err(), Some(ParseError::TooFewSpots { expected: 15, found: 0 }));
+        assert_eq!(
+            Day23::parse(".. . . . ..\n  B C B D\n  A D C B").err(),
+            Some(ParseError::WrongCount { amphipod: 'A', expected: 2, found: 1 })
+        );
+    }
+
     #[test]
     fn test_hash() {
         let inner = [ 0,0,0,0,0,0,0 ];
-        let board1 = Board::<7>::with_loss(inner.clone(), 0, 0);
+        let board1 = Board::<7>::with_loss(inner, 0, 0);
         let board2 = Board::<7>::with_loss(inner, 10, 10);
         assert_eq!(&board1, &board2);
 
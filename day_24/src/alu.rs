@@ -0,0 +1,223 @@
+//! The ALU from the puzzle: four registers, six instructions, and a stream
+//! of input digits that `inp` reads from.
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    pub const ALL: [Register; 4] = [Register::W, Register::X, Register::Y, Register::Z];
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "w" => Some(Register::W),
+            "x" => Some(Register::X),
+            "y" => Some(Register::Y),
+            "z" => Some(Register::Z),
+            _ => None,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let name = match self {
+            Register::W => "w",
+            Register::X => "x",
+            Register::Y => "y",
+            Register::Z => "z",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The second argument of everything but `inp`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Operand::Register(r) => write!(f, "{r}"),
+            Operand::Number(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl Instruction {
+    /// The register the instruction writes to
+    pub fn target(&self) -> Register {
+        match *self {
+            Instruction::Inp(a)
+            | Instruction::Add(a, _)
+            | Instruction::Mul(a, _)
+            | Instruction::Div(a, _)
+            | Instruction::Mod(a, _)
+            | Instruction::Eql(a, _) => a,
+        }
+    }
+
+    /// The registers whose old value the instruction needs. `mul a 0` doesn't
+    /// care what was in `a`, which is how MONAD clears its registers.
+    pub fn reads(&self) -> Vec<Register> {
+        let (a, b) = match *self {
+            Instruction::Inp(_) => return Vec::new(),
+            Instruction::Mul(_, Operand::Number(0)) => return Vec::new(),
+            Instruction::Add(a, b)
+            | Instruction::Mul(a, b)
+            | Instruction::Div(a, b)
+            | Instruction::Mod(a, b)
+            | Instruction::Eql(a, b) => (a, b),
+        };
+        match b {
+            Operand::Register(b) => vec![a, b],
+            Operand::Number(_) => vec![a],
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Instruction::Inp(a) => write!(f, "inp {a}"),
+            Instruction::Add(a, b) => write!(f, "add {a} {b}"),
+            Instruction::Mul(a, b) => write!(f, "mul {a} {b}"),
+            Instruction::Div(a, b) => write!(f, "div {a} {b}"),
+            Instruction::Mod(a, b) => write!(f, "mod {a} {b}"),
+            Instruction::Eql(a, b) => write!(f, "eql {a} {b}"),
+        }
+    }
+}
+
+/// The things the puzzle says make the ALU crash
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RunError {
+    DivideByZero { step: usize },
+    /// `mod a b` with `a < 0` or `b <= 0`
+    BadModulo { step: usize, a: i64, b: i64 },
+    OutOfInput { step: usize },
+    /// A result too big for an `i64`, which a real ALU would get wrong
+    Overflow { step: usize },
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RunError::DivideByZero { step } => write!(f, "step {step}: division by zero"),
+            RunError::BadModulo { step, a, b } => write!(f, "step {step}: can't take {a} mod {b}"),
+            RunError::OutOfInput { step } => write!(f, "step {step}: ran out of input"),
+            RunError::Overflow { step } => write!(f, "step {step}: the result doesn't fit in an i64"),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+/// What the ALU looked like right after one instruction, for tracing
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Step {
+    /// Which instruction of the program this was, counting from 0
+    pub index: usize,
+    pub instruction: Instruction,
+    pub registers: [i64; 4],
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let [w, x, y, z] = self.registers;
+        write!(f, "{:>4}  {:<12} w={w} x={x} y={y} z={z}", self.index, self.instruction.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Alu {
+    pub registers: [i64; 4],
+}
+
+impl Alu {
+    pub fn get(&self, register: Register) -> i64 {
+        self.registers[register.index()]
+    }
+
+    pub fn set(&mut self, register: Register, value: i64) {
+        self.registers[register.index()] = value;
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(r) => self.get(r),
+            Operand::Number(n) => n,
+        }
+    }
+
+    /// Runs the whole program, reading `inp`s from `input`
+    pub fn run(
+        &mut self,
+        program: &[Instruction],
+        input: impl IntoIterator<Item = i64>,
+    ) -> Result<(), RunError> {
+        self.run_traced(program, input, |_| ())
+    }
+
+    /// Like [`Alu::run`], but hands every step to `trace` as it goes
+    pub fn run_traced(
+        &mut self,
+        program: &[Instruction],
+        input: impl IntoIterator<Item = i64>,
+        mut trace: impl FnMut(&Step),
+    ) -> Result<(), RunError> {
+        let mut input = input.into_iter();
+        for (step, &instruction) in program.iter().enumerate() {
+            self.execute(instruction, step, &mut input)?;
+            trace(&Step { index: step, instruction, registers: self.registers });
+        }
+        Ok(())
+    }
+
+    fn execute(
+        &mut self,
+        instruction: Instruction,
+        step: usize,
+        input: &mut impl Iterator<Item = i64>,
+    ) -> Result<(), RunError> {
+        let overflow = RunError::Overflow { step };
+        let (a, value) = match instruction {
+            Instruction::Inp(a) => (a, input.next().ok_or(RunError::OutOfInput { step })?),
+            Instruction::Add(a, b) => (a, self.get(a).checked_add(self.value(b)).ok_or(overflow)?),
+            Instruction::Mul(a, b) => (a, self.get(a).checked_mul(self.value(b)).ok_or(overflow)?),
+            Instruction::Div(a, b) => match self.value(b) {
+                0 => return Err(RunError::DivideByZero { step }),
+                b => (a, self.get(a).checked_div(b).ok_or(overflow)?),
+            },
+            Instruction::Mod(a, b) => match (self.get(a), self.value(b)) {
+                (x, y) if x < 0 || y <= 0 => return Err(RunError::BadModulo { step, a: x, b: y }),
+                (x, y) => (a, x % y),
+            },
+            Instruction::Eql(a, b) => (a, (self.get(a) == self.value(b)) as i64),
+        };
+        self.set(a, value);
+        Ok(())
+    }
+}
@@ -0,0 +1,493 @@
+//! Turns MONAD back into something a person can read: each digit's block
+//! becomes one expression for the new z, folded down as far as it goes,
+//! and the blocks that push a digit onto z (in base 26) or pop one off get
+//! recognised, so that the digits' constraints on each other fall out.
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Rem},
+};
+
+use crate::{
+    alu::{Instruction, Operand, Register},
+    Monad,
+};
+
+/// A register's value in terms of the digit read in this block and the z
+/// the block started with
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expr {
+    Num(i64),
+    /// The `n`th digit of the model number, counting from 1
+    Digit(usize),
+    /// z as the previous block left it
+    Z,
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Mod(Box<Expr>, Box<Expr>),
+    /// 1 if both sides are equal, otherwise 0
+    Eq(Box<Expr>, Box<Expr>),
+    /// 1 if they aren't, which is what `eql (eql a b) 0` really means
+    Ne(Box<Expr>, Box<Expr>),
+}
+
+use Expr::{Digit, Num, Z};
+
+// The arithmetic folds constants as it goes, and keeps any constant on the
+// right so that `d1 + 3 + 4` becomes `d1 + 7`. Constants that would
+// overflow an i64 are left unfolded, the way the ALU would have to leave
+// them.
+impl Add for Expr {
+    type Output = Expr;
+
+    fn add(self, rhs: Expr) -> Expr {
+        match (self, rhs) {
+            (Num(a), Num(b)) if a.checked_add(b).is_some() => Num(a + b),
+            (Num(0), e) | (e, Num(0)) => e,
+            (Num(n), e) if !matches!(e, Num(_)) => e + Num(n),
+            (Expr::Add(e, n), Num(m)) if matches!(*n, Num(n) if n.checked_add(m).is_some()) => {
+                let Num(n) = *n else { unreachable!() };
+                *e + Num(n + m)
+            }
+            (a, b) => Expr::Add(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Mul for Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Expr {
+        match (self, rhs) {
+            (Num(a), Num(b)) if a.checked_mul(b).is_some() => Num(a * b),
+            (Num(0), _) | (_, Num(0)) => Num(0),
+            (Num(1), e) | (e, Num(1)) => e,
+            (Num(n), e) if !matches!(e, Num(_)) => Expr::Mul(Box::new(e), Box::new(Num(n))),
+            (a, b) => Expr::Mul(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Div for Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Expr {
+        match (self, rhs) {
+            (Num(a), Num(b)) if a.checked_div(b).is_some() => Num(a / b),
+            (e, Num(1)) => e,
+            (Num(0), _) => Num(0),
+            (a, b) => Expr::Div(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Rem for Expr {
+    type Output = Expr;
+
+    fn rem(self, rhs: Expr) -> Expr {
+        match (self, rhs) {
+            (Num(a), Num(b)) if a >= 0 && b > 0 => Num(a % b),
+            (Num(0), _) => Num(0),
+            (a, Num(m)) if m > 0 && a.range().0 >= 0 && a.range().1 < m => a,
+            (a, b) => Expr::Mod(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Expr {
+    /// `eql`, which is where most of the folding happens: comparing things
+    /// that can never be equal (like a digit and something of 10 or more)
+    /// is just 0, and comparing a comparison with 0 flips it.
+    pub fn eql(a: Expr, b: Expr) -> Expr {
+        let ((a_lo, a_hi), (b_lo, b_hi)) = (a.range(), b.range());
+        if a_hi < b_lo || b_hi < a_lo {
+            return Num(0);
+        }
+        match (a, b) {
+            (Num(a), Num(b)) => Num((a == b) as i64),
+            (Expr::Eq(x, y), Num(0)) | (Num(0), Expr::Eq(x, y)) => Expr::Ne(x, y),
+            (Expr::Ne(x, y), Num(0)) | (Num(0), Expr::Ne(x, y)) => Expr::Eq(x, y),
+            (e @ (Expr::Eq(..) | Expr::Ne(..)), Num(1)) | (Num(1), e @ (Expr::Eq(..) | Expr::Ne(..))) => e,
+            (a, b) => Expr::Eq(Box::new(a), Box::new(b)),
+        }
+    }
+
+    /// The smallest and largest the expression could be, as far as can be
+    /// told without knowing z
+    pub fn range(&self) -> (i64, i64) {
+        match self {
+            Num(n) => (*n, *n),
+            Digit(_) => (1, 9),
+            Z => (i64::MIN, i64::MAX),
+            Expr::Add(a, b) => {
+                let ((a_lo, a_hi), (b_lo, b_hi)) = (a.range(), b.range());
+                (a_lo.saturating_add(b_lo), a_hi.saturating_add(b_hi))
+            }
+            Expr::Mul(a, b) => {
+                let ((a_lo, a_hi), (b_lo, b_hi)) = (a.range(), b.range());
+                let corners = [
+                    a_lo.saturating_mul(b_lo),
+                    a_lo.saturating_mul(b_hi),
+                    a_hi.saturating_mul(b_lo),
+                    a_hi.saturating_mul(b_hi),
+                ];
+                (*corners.iter().min().unwrap(), *corners.iter().max().unwrap())
+            }
+            Expr::Div(a, b) => match **b {
+                Num(k) if k > 0 => (a.range().0 / k, a.range().1 / k),
+                _ => (i64::MIN, i64::MAX),
+            },
+            // `mod` crashes the ALU unless both sides are positive
+            Expr::Mod(_, b) => (0, b.range().1.saturating_sub(1).max(0)),
+            Expr::Eq(..) | Expr::Ne(..) => (0, 1),
+        }
+    }
+
+    pub fn contains(&self, other: &Expr) -> bool {
+        self == other
+            || match self {
+                Num(_) | Digit(_) | Z => false,
+                Expr::Add(a, b)
+                | Expr::Mul(a, b)
+                | Expr::Div(a, b)
+                | Expr::Mod(a, b)
+                | Expr::Eq(a, b)
+                | Expr::Ne(a, b) => a.contains(other) || b.contains(other),
+            }
+    }
+
+    /// The expression with every `from` in it replaced by `to`, folded again
+    pub fn replace(&self, from: &Expr, to: &Expr) -> Expr {
+        if self == from {
+            return to.clone();
+        }
+        match self {
+            Num(_) | Digit(_) | Z => self.clone(),
+            Expr::Add(a, b) => a.replace(from, to) + b.replace(from, to),
+            Expr::Mul(a, b) => a.replace(from, to) * b.replace(from, to),
+            Expr::Div(a, b) => a.replace(from, to) / b.replace(from, to),
+            Expr::Mod(a, b) => a.replace(from, to) % b.replace(from, to),
+            Expr::Eq(a, b) => Expr::eql(a.replace(from, to), b.replace(from, to)),
+            Expr::Ne(a, b) => Expr::eql(Expr::eql(a.replace(from, to), b.replace(from, to)), Num(0)),
+        }
+    }
+
+    /// The first comparison in the expression, if there is one
+    fn condition(&self) -> Option<&Expr> {
+        match self {
+            Num(_) | Digit(_) | Z => None,
+            Expr::Eq(..) | Expr::Ne(..) => Some(self),
+            Expr::Add(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) | Expr::Mod(a, b) => {
+                a.condition().or_else(|| b.condition())
+            }
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Add(..) => 1,
+            Expr::Mul(..) | Expr::Div(..) | Expr::Mod(..) => 2,
+            Num(n) if *n < 0 => 1,
+            // Comparisons get square brackets of their own
+            Num(_) | Digit(_) | Z | Expr::Eq(..) | Expr::Ne(..) => 3,
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        // The right side of / and % needs brackets at the same precedence
+        // too, since (a / b) / c isn't a / (b / c)
+        let side = |f: &mut Formatter, e: &Expr, min: u8| {
+            if e.precedence() < min { write!(f, "({e})") } else { write!(f, "{e}") }
+        };
+        let binary = |f: &mut Formatter, a: &Expr, op: &str, b: &Expr, level: u8| {
+            side(f, a, level)?;
+            write!(f, " {op} ")?;
+            side(f, b, level + 1)
+        };
+        match self {
+            Num(n) => write!(f, "{n}"),
+            Digit(n) => write!(f, "d{n}"),
+            Z => write!(f, "z"),
+            Expr::Add(a, b) => match **b {
+                Num(n) if n < 0 => {
+                    side(f, a, 1)?;
+                    write!(f, " - {}", n.unsigned_abs())
+                }
+                _ => {
+                    side(f, a, 1)?;
+                    write!(f, " + ")?;
+                    side(f, b, 1)
+                }
+            },
+            Expr::Mul(a, b) => {
+                side(f, a, 2)?;
+                write!(f, " * ")?;
+                side(f, b, 2)
+            }
+            Expr::Div(a, b) => binary(f, a, "/", b, 2),
+            Expr::Mod(a, b) => binary(f, a, "%", b, 2),
+            Expr::Eq(a, b) => write!(f, "[{a} == {b}]"),
+            Expr::Ne(a, b) => write!(f, "[{a} != {b}]"),
+        }
+    }
+}
+
+/// What a block does to z, when it's one of the two MONAD is made of
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// `z = z * base + value`
+    Push { base: i64, value: Expr },
+    /// `z = z / base`, as long as `digit == z % base + offset`. Otherwise
+    /// the top gets swapped for something else and z can't get back to 0,
+    /// but it never ends up less than `z / base` either way.
+    Pop { base: i64, offset: i64 },
+    /// Anything else
+    Other,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Block {
+    /// Which digit this block reads, counting from 1
+    pub digit: usize,
+    /// The new z
+    pub z: Expr,
+    pub kind: Kind,
+}
+
+/// `d{digit} = d{other} + offset`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Constraint {
+    pub digit: usize,
+    pub other: usize,
+    pub offset: i64,
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let Constraint { digit, other, offset } = *self;
+        match offset {
+            0 => write!(f, "d{digit} = d{other}"),
+            n if n < 0 => write!(f, "d{digit} = d{other} - {}", n.unsigned_abs()),
+            n => write!(f, "d{digit} = d{other} + {n}"),
+        }
+    }
+}
+
+pub struct Analysis {
+    pub blocks: Vec<Block>,
+    /// `None` when some block didn't fit the pattern, or the pushes and pops
+    /// don't pair up, so nothing can be said about the digits this way
+    pub constraints: Option<Vec<Constraint>>,
+}
+
+pub fn decompile(monad: &Monad) -> Analysis {
+    let blocks: Vec<Block> = monad.blocks().enumerate().map(|(i, block)| run(block, i + 1)).collect();
+    let constraints = pair_up(&blocks);
+    Analysis { blocks, constraints }
+}
+
+/// Runs a block with expressions in the registers instead of numbers
+fn run(block: &[Instruction], digit: usize) -> Block {
+    let mut registers = [Num(0), Num(0), Num(0), Z];
+    let index = |r: Register| Register::ALL.iter().position(|&x| x == r).unwrap();
+    for ins in block {
+        let a = index(ins.target());
+        let operand = |b: Operand, registers: &[Expr; 4]| match b {
+            Operand::Register(r) => registers[index(r)].clone(),
+            Operand::Number(n) => Num(n),
+        };
+        let old = registers[a].clone();
+        registers[a] = match *ins {
+            Instruction::Inp(_) => Digit(digit),
+            Instruction::Add(_, b) => old + operand(b, &registers),
+            Instruction::Mul(_, b) => old * operand(b, &registers),
+            Instruction::Div(_, b) => old / operand(b, &registers),
+            Instruction::Mod(_, b) => old % operand(b, &registers),
+            Instruction::Eql(_, b) => Expr::eql(old, operand(b, &registers)),
+        };
+    }
+    let z = registers[3].clone();
+    let kind = kind(&z, digit);
+    Block { digit, z, kind }
+}
+
+fn kind(z: &Expr, digit: usize) -> Kind {
+    // z * base + value, where value is a single base 26 digit
+    if let Expr::Add(pushed, value) = z {
+        if let Expr::Mul(old, base) = &**pushed {
+            if let (Z, Num(base)) = (&**old, &**base) {
+                let (lo, hi) = value.range();
+                if !value.contains(&Z) && lo >= 0 && hi < *base {
+                    return Kind::Push { base: *base, value: (**value).clone() };
+                }
+            }
+        }
+    }
+
+    // With the condition false the block has to come down to z / base, and
+    // the condition has to be the digit against z % base + offset
+    let Some(condition @ Expr::Ne(a, b)) = z.condition() else { return Kind::Other };
+    let (top, offset) = match &**a {
+        Expr::Add(top, offset) => match **offset {
+            Num(offset) => (&**top, offset),
+            _ => return Kind::Other,
+        },
+        top => (top, 0),
+    };
+    let (Expr::Mod(old, base), Digit(d)) = (top, &**b) else { return Kind::Other };
+    let (Z, Num(base), true) = (&**old, &**base, *d == digit) else { return Kind::Other };
+    if z.replace(condition, &Num(0)) == Z / Num(*base) && swaps_top(&z.replace(condition, &Num(1)), *base) {
+        Kind::Pop { base: *base, offset }
+    } else {
+        Kind::Other
+    }
+}
+
+/// Whether `z` is `z / base * base` plus things that can't be negative or
+/// depend on z, so that it never drops below `z / base`
+fn swaps_top(z: &Expr, base: i64) -> bool {
+    let mut low = 0;
+    let mut rest = z;
+    while let Expr::Add(left, right) = rest {
+        if right.contains(&Z) {
+            return false;
+        }
+        low = right.range().0.saturating_add(low);
+        rest = left;
+    }
+    low >= 0 && *rest == Z / Num(base) * Num(base)
+}
+
+/// Matches every pop with the push it undoes. Each pop only keeps z from
+/// growing when its digit equals the pushed value plus its offset.
+fn pair_up(blocks: &[Block]) -> Option<Vec<Constraint>> {
+    let mut stack = Vec::new();
+    let mut constraints = Vec::new();
+    for block in blocks {
+        match &block.kind {
+            Kind::Push { value, .. } => stack.push(value.clone()),
+            Kind::Pop { offset, .. } => {
+                // The pushed value has to be one digit plus a constant
+                let (other, pushed) = match stack.pop()? {
+                    Digit(other) => (other, 0),
+                    Expr::Add(d, n) => match (*d, *n) {
+                        (Digit(other), Num(n)) => (other, n),
+                        _ => return None,
+                    },
+                    _ => return None,
+                };
+                constraints.push(Constraint { digit: block.digit, other, offset: pushed.checked_add(*offset)? });
+            }
+            Kind::Other => return None,
+        }
+    }
+    stack.is_empty().then_some(constraints)
+}
+
+impl Analysis {
+    /// The largest and smallest model numbers the constraints allow,
+    /// worked out the way you would by hand
+    pub fn model_numbers(&self) -> Option<(String, String)> {
+        let constraints = self.constraints.as_ref()?;
+        let mut largest = vec![9; self.blocks.len()];
+        let mut smallest = vec![1; self.blocks.len()];
+        for c in constraints {
+            let (digit, other) = (c.digit - 1, c.other - 1);
+            if c.offset.unsigned_abs() > 8 {
+                return None;
+            }
+            largest[other] = 9.min(9 - c.offset);
+            largest[digit] = largest[other] + c.offset;
+            smallest[other] = 1.max(1 - c.offset);
+            smallest[digit] = smallest[other] + c.offset;
+        }
+        let number = |digits: Vec<i64>| digits.iter().map(i64::to_string).collect();
+        Some((number(largest), number(smallest)))
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for block in &self.blocks {
+            write!(f, "d{:<2}  z = {}", block.digit, block.z)?;
+            match &block.kind {
+                Kind::Push { base, value } => writeln!(f, "\n      push {value} (base {base})")?,
+                Kind::Pop { base, offset } => {
+                    let top = Expr::Mod(Box::new(Z), Box::new(Num(*base))) + Num(*offset);
+                    writeln!(f, "\n      pop (base {base}), as long as d{} == {top}", block.digit)?
+                }
+                Kind::Other => writeln!(f, "\n      doesn't look like a push or a pop")?,
+            }
+        }
+
+        let Some(constraints) = &self.constraints else {
+            return writeln!(f, "\nThe blocks don't pair up into pushes and pops, so no constraints.");
+        };
+        writeln!(f, "\nFor z to end up 0 every pop has to match:")?;
+        for c in constraints {
+            writeln!(f, "  {c}")?;
+        }
+        match self.model_numbers() {
+            Some((largest, smallest)) => {
+                writeln!(f, "\nLargest model number:  {largest}")?;
+                write!(f, "Smallest model number: {smallest}")
+            }
+            None => write!(f, "\nNo digits from 1 to 9 can satisfy all of them."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day24;
+    use common::Solution;
+
+    const MONAD: &str = include_str!("../examples/monad.txt");
+
+    #[test]
+    fn folding() {
+        let ne = Expr::eql(Expr::eql(Z % Num(26) + Num(12), Digit(1)), Num(0));
+        assert_eq!(ne, Num(1), "z % 26 + 12 is never a digit");
+
+        let ne = Expr::eql(Expr::eql(Z % Num(26) + Num(-3), Digit(2)), Num(0));
+        assert!(matches!(ne, Expr::Ne(..)));
+        assert_eq!(ne.to_string(), "[z % 26 - 3 != d2]");
+        assert_eq!(Digit(1) + Num(4) + Num(-4), Digit(1));
+        assert_eq!((Z / Num(1) * Num(26)).to_string(), "z * 26");
+    }
+
+    #[test]
+    fn constraints() {
+        let monad = Day24::parse(MONAD).unwrap();
+        let analysis = decompile(&monad);
+        assert!(matches!(analysis.blocks[0].kind, Kind::Push { base: 26, .. }));
+        assert!(matches!(analysis.blocks[13].kind, Kind::Pop { base: 26, .. }));
+        assert_eq!(analysis.constraints.as_ref().map(Vec::len), Some(7));
+        assert_eq!(
+            analysis.model_numbers(),
+            Day24::part1(&monad).0.zip(Day24::part2(&monad).0)
+        );
+    }
+
+    #[test]
+    fn resets_are_not_pops() {
+        let monad = Day24::parse("inp w\nadd z w\ninp w\nmul z 0").unwrap();
+        let analysis = decompile(&monad);
+        assert_eq!(analysis.blocks[1].kind, Kind::Other);
+        assert_eq!(analysis.constraints, None);
+    }
+
+    #[test]
+    fn overflowing_constants() {
+        assert_eq!((Digit(1) + Num(i64::MAX) + Num(1)).to_string(), "d1 + 9223372036854775807 + 1");
+        assert_eq!((Digit(1) + Num(i64::MIN)).to_string(), "d1 - 9223372036854775808");
+        assert_eq!(Num(i64::MIN) / Num(-1), Expr::Div(Box::new(Num(i64::MIN)), Box::new(Num(-1))));
+
+        let program = Day24::parse("inp w\nadd w 9223372036854775807\nadd w 1").unwrap();
+        assert_eq!(decompile(&program).blocks[0].kind, Kind::Other);
+        assert_eq!(Day24::part1(&program).0, None);
+    }
+}
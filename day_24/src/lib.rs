@@ -0,0 +1,355 @@
+pub mod alu;
+pub mod decompile;
+
+use std::{collections::HashSet, fmt::{Display, Formatter}, iter::once, ops::Range};
+use common::{Position, Solution};
+use crate::{
+    alu::{Alu, Instruction, Operand, Register},
+    decompile::{decompile, Kind},
+};
+
+/// What can go wrong reading the MONAD program
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Something other than what the instruction needs at that point
+    Expected { at: Position, expected: &'static str, found: String },
+    /// A register read before this digit's block set it, so that more than
+    /// `z` would have to be remembered from one digit to the next
+    Carries { line: usize, register: Register },
+    /// No `inp`, so no digits to search for
+    NoInput,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::Expected { at, expected, found } => {
+                write!(f, "{at}: expected {expected}, found `{found}`")
+            }
+            ParseError::Carries { line, register } => write!(
+                f,
+                "line {line}: `{register}` is used before it is set, but only z can be carried over from the last digit"
+            ),
+            ParseError::NoInput => write!(f, "there is no `inp` to read a digit with"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut program = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let mut tokens = line.split_whitespace();
+        let Some(op) = tokens.next() else { continue };
+        let expected = |expected, found: Option<&str>| ParseError::Expected {
+            at: Position::of(i, line, found.unwrap_or(&line[line.len()..])),
+            expected,
+            found: found.unwrap_or("the end of the line").to_string(),
+        };
+
+        // `None` for `inp`, which is the only one without a second operand
+        let binary: Option<fn(Register, Operand) -> Instruction> = match op {
+            "inp" => None,
+            "add" => Some(Instruction::Add),
+            "mul" => Some(Instruction::Mul),
+            "div" => Some(Instruction::Div),
+            "mod" => Some(Instruction::Mod),
+            "eql" => Some(Instruction::Eql),
+            _ => return Err(expected("an instruction", Some(op))),
+        };
+
+        let a = tokens.next();
+        let a = a.and_then(Register::parse).ok_or(expected("a register", a))?;
+        let instruction = match binary {
+            None => Instruction::Inp(a),
+            Some(binary) => {
+                let b = tokens.next();
+                let b = b
+                    .and_then(|b| match Register::parse(b) {
+                        Some(r) => Some(Operand::Register(r)),
+                        None => b.parse().ok().map(Operand::Number),
+                    })
+                    .ok_or(expected("a register or a number", b))?;
+                binary(a, b)
+            }
+        };
+        if let Some(extra) = tokens.next() {
+            return Err(expected("the end of the line", Some(extra)));
+        }
+        program.push(instruction);
+    }
+    Ok(program)
+}
+
+/// The model number checker, cut up into one block per digit. Each block
+/// starts with an `inp` and only ever looks at the `z` the last one left
+/// behind, so where the search has got to is just the block and `z`.
+pub struct Monad {
+    pub program: Vec<Instruction>,
+    blocks: Vec<Range<usize>>,
+}
+
+impl Monad {
+    pub fn new(program: Vec<Instruction>) -> Result<Self, ParseError> {
+        let mut starts: Vec<usize> = program
+            .iter()
+            .enumerate()
+            .filter(|(_, ins)| matches!(ins, Instruction::Inp(_)))
+            .map(|(i, _)| i)
+            .collect();
+        if starts.is_empty() {
+            return Err(ParseError::NoInput);
+        }
+        // Anything before the first `inp` counts as part of the first block
+        if starts.first() != Some(&0) {
+            starts.insert(0, 0);
+        }
+        let ends = starts.iter().skip(1).copied().chain(once(program.len()));
+        let blocks: Vec<Range<usize>> = starts.iter().zip(ends).map(|(&s, e)| s..e).collect();
+
+        for block in &blocks {
+            let mut set = vec![Register::Z];
+            if block.start == 0 {
+                set = Register::ALL.to_vec(); // everything starts at 0
+            }
+            for i in block.clone() {
+                if let Some(&register) = program[i].reads().iter().find(|r| !set.contains(r)) {
+                    return Err(ParseError::Carries { line: i + 1, register });
+                }
+                set.push(program[i].target());
+            }
+        }
+        Ok(Monad { program, blocks })
+    }
+
+    pub fn digits(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// The instructions of each digit's block, in order
+    pub fn blocks(&self) -> impl Iterator<Item = &[Instruction]> {
+        self.blocks.iter().map(|block| &self.program[block.clone()])
+    }
+
+    /// Runs one digit's block, giving the `z` it leaves for the next one.
+    /// `None` if the ALU would crash.
+    fn run_block(&self, block: usize, digit: i64, z: i64) -> Option<i64> {
+        let mut alu = Alu::default();
+        alu.set(Register::Z, z);
+        alu.run(&self.program[self.blocks[block].clone()], once(digit)).ok()?;
+        Some(alu.get(Register::Z))
+    }
+
+    /// The first model number MONAD accepts, trying the digits of every
+    /// position in the order given, so 9 to 1 finds the largest one.
+    pub fn search(&self, order: [i64; 9]) -> Option<String> {
+        let limits = self.limits();
+        let mut dead = HashSet::new();
+        let found = self.find(0, 0, &order, limits.as_deref(), &mut dead);
+        common::debug!(1, "ruled out {} (digit, z) states", dead.len());
+        found
+    }
+
+    fn find(
+        &self,
+        block: usize,
+        z: i64,
+        order: &[i64; 9],
+        limits: Option<&[i64]>,
+        dead: &mut HashSet<(usize, i64)>,
+    ) -> Option<String> {
+        if block == self.blocks.len() {
+            return (z == 0).then(String::new);
+        }
+        if limits.is_some_and(|limits| z >= limits[block]) || dead.contains(&(block, z)) {
+            return None;
+        }
+        for &digit in order {
+            let Some(next) = self.run_block(block, digit, z) else { continue };
+            if let Some(rest) = self.find(block + 1, next, order, limits, dead) {
+                return Some(format!("{digit}{rest}"));
+            }
+        }
+        dead.insert((block, z));
+        None
+    }
+
+    /// The most z can be going into each block and still get down to 0 by
+    /// the end, when every block pushes onto z or pops off it like MONAD's
+    /// do. A push never makes z smaller and a pop leaves at least z / base
+    /// behind, so that is the product of the bases of the pops still to
+    /// come, and anything bigger is a dead end. `None` for any other
+    /// program, since a block that resets z could get back to 0 from
+    /// anywhere.
+    fn limits(&self) -> Option<Vec<i64>> {
+        let blocks = decompile(self).blocks;
+        let mut limits = vec![1i64; blocks.len() + 1];
+        for (i, block) in blocks.iter().enumerate().rev() {
+            limits[i] = match block.kind {
+                Kind::Push { .. } => limits[i + 1],
+                Kind::Pop { base, .. } => limits[i + 1].saturating_mul(base),
+                Kind::Other => return None,
+            };
+        }
+        Some(limits)
+    }
+
+    /// Prints every step of checking `number` when -vv asks for it
+    fn trace(&self, number: &str) {
+        if common::verbosity() < 2 {
+            return;
+        }
+        let digits = number.bytes().map(|b| (b - b'0') as i64);
+        let result = Alu::default().run_traced(&self.program, digits, |step| common::debug!(2, "{step}"));
+        if let Err(e) = result {
+            common::debug!(2, "{e}");
+        }
+    }
+}
+
+/// The model number a search found, or that there isn't one
+pub struct ModelNumber(pub Option<String>);
+
+impl Display for ModelNumber {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match &self.0 {
+            Some(number) => write!(f, "{number}"),
+            None => write!(f, "none, MONAD accepts no model number"),
+        }
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Monad;
+    type Error = ParseError;
+    type Answer1 = ModelNumber;
+    type Answer2 = ModelNumber;
+    const DAY: u8 = 24;
+
+    fn parse(input: &str) -> Result<Monad, ParseError> {
+        Monad::new(parse_program(input)?)
+    }
+
+    fn part1(monad: &Monad) -> ModelNumber {
+        let largest = monad.search([9, 8, 7, 6, 5, 4, 3, 2, 1]);
+        if let Some(largest) = &largest {
+            monad.trace(largest);
+        }
+        ModelNumber(largest)
+    }
+
+    fn part2(monad: &Monad) -> ModelNumber {
+        let smallest = monad.search([1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        if let Some(smallest) = &smallest {
+            monad.trace(smallest);
+        }
+        ModelNumber(smallest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONAD: &str = include_str!("../examples/monad.txt");
+
+    fn run(program: &str, input: &[i64]) -> [i64; 4] {
+        let mut alu = Alu::default();
+        alu.run(&parse_program(program).unwrap(), input.iter().copied()).unwrap();
+        alu.registers
+    }
+
+    #[test]
+    fn puzzle_programs() {
+        assert_eq!(run("inp x\nmul x -1", &[7])[1], -7);
+        let three_times = "inp z\ninp x\nmul z 3\neql z x";
+        assert_eq!(run(three_times, &[2, 6])[3], 1);
+        assert_eq!(run(three_times, &[2, 5])[3], 0);
+        let binary = "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\n\
+                      add x w\nmod x 2\ndiv w 2\nmod w 2";
+        assert_eq!(run(binary, &[13]), [1, 1, 0, 1]);
+    }
+
+    #[test]
+    fn crashes() {
+        let mut alu = Alu::default();
+        let program = parse_program("inp w\nmod w 5").unwrap();
+        assert_eq!(
+            alu.run(&program, [-3]),
+            Err(alu::RunError::BadModulo { step: 1, a: -3, b: 5 })
+        );
+        assert_eq!(alu.run(&program, []), Err(alu::RunError::OutOfInput { step: 0 }));
+        let program = parse_program("div x y").unwrap();
+        assert_eq!(Alu::default().run(&program, []), Err(alu::RunError::DivideByZero { step: 0 }));
+    }
+
+    #[test]
+    fn overflows() {
+        let overflow = |program: &str, input: &[i64]| {
+            Alu::default().run(&parse_program(program).unwrap(), input.iter().copied()).err()
+        };
+        assert_eq!(overflow("inp w\nadd w 9223372036854775807", &[1]), Some(alu::RunError::Overflow { step: 1 }));
+        assert_eq!(
+            overflow("inp w\nmul w 1000000000000\nmul w 1000000000000", &[1]),
+            Some(alu::RunError::Overflow { step: 2 })
+        );
+        assert_eq!(
+            overflow("inp w\nmul w -9223372036854775808\ninp x\ndiv w x", &[1, -1]),
+            Some(alu::RunError::Overflow { step: 3 })
+        );
+        assert_eq!(overflow("inp w\nadd w 9223372036854775806", &[1]), None);
+    }
+
+    #[test]
+    fn bad_programs() {
+        assert!(matches!(parse_program("inp q"), Err(ParseError::Expected { expected: "a register", .. })));
+        assert!(matches!(parse_program("add x"), Err(ParseError::Expected { .. })));
+        assert!(matches!(parse_program("sub x 1"), Err(ParseError::Expected { expected: "an instruction", .. })));
+        for (program, op) in [("x", "x"), ("foo w", "foo")] {
+            assert_eq!(
+                parse_program(program).err(),
+                Some(ParseError::Expected {
+                    at: Position { line: 1, column: 1 },
+                    expected: "an instruction",
+                    found: op.to_string(),
+                })
+            );
+        }
+        assert!(matches!(parse_program("add x 1 2"), Err(ParseError::Expected { .. })));
+        assert!(matches!(
+            Day24::parse("inp w\nadd x 1\ninp w\nadd z x"),
+            Err(ParseError::Carries { line: 4, register: Register::X })
+        ));
+        assert!(matches!(Day24::parse("add z 1"), Err(ParseError::NoInput)));
+    }
+
+    #[test]
+    fn model_numbers() {
+        let monad = Day24::parse(MONAD).unwrap();
+        assert_eq!(monad.digits(), 14);
+        let largest = Day24::part1(&monad).0.unwrap();
+        let smallest = Day24::part2(&monad).0.unwrap();
+        assert_eq!(largest, "79979519292999");
+        assert_eq!(smallest, "19315111181813");
+
+        for number in [largest, smallest] {
+            let mut alu = Alu::default();
+            alu.run(&monad.program, number.bytes().map(|b| (b - b'0') as i64)).unwrap();
+            assert_eq!(alu.get(Register::Z), 0);
+        }
+    }
+
+    #[test]
+    fn other_programs() {
+        // The second block throws away whatever the first one left in z
+        let resets = Day24::parse("inp w\nadd z w\ninp w\nmul z 0").unwrap();
+        assert_eq!(Day24::part1(&resets).0.as_deref(), Some("99"));
+        assert_eq!(Day24::part2(&resets).0.as_deref(), Some("11"));
+        let never = Day24::parse("inp w\nadd z w").unwrap();
+        assert_eq!(Day24::part1(&never).0, None);
+        assert_eq!(Day24::part1(&never).to_string(), "none, MONAD accepts no model number");
+    }
+}
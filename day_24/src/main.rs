@@ -1,3 +1,35 @@
-fn main() {
-    println!("Hello, world!");
+use std::process::ExitCode;
+
+use common::{Solution, Source};
+use day_24::{decompile::decompile, Day24};
+
+const DECOMPILE_USAGE: &str = "\
+Usage: day_24 --decompile [PATH | - | --example]
+
+Prints what each block of the program does, and the constraints between
+the digits when the blocks fit the usual pattern.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.split_first() {
+        Some((flag, rest)) if flag == "--decompile" => match print_decompiled(rest) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {e}");
+                ExitCode::FAILURE
+            }
+        },
+        _ => common::main::<Day24>(),
+    }
+}
+
+/// `day_24 --decompile` prints what each block of the program does instead
+/// of searching for the model numbers
+fn print_decompiled(args: &[String]) -> Result<(), String> {
+    let source = Source::from_args(&mut args.iter(), |_, _| Ok(false))
+        .map_err(|e| format!("{e}\n\n{DECOMPILE_USAGE}"))?;
+    let input = source.read(Day24::EXAMPLE)?;
+    let monad = Day24::parse(&input).map_err(|e| format!("bad input in {source}: {e}"))?;
+    println!("{}", decompile(&monad));
+    Ok(())
 }
@@ -0,0 +1,260 @@
+use std::fmt::{Display, Formatter};
+use common::Solution;
+use grid::{Cell, Grid};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Spot {
+    Empty,
+    East,
+    South,
+}
+
+impl Cell for Spot {
+    const EXPECTED: &'static str = "`>`, `v` or `.`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Spot::Empty),
+            '>' => Some(Spot::East),
+            'v' => Some(Spot::South),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Spot::Empty => '.',
+            Spot::East => '>',
+            Spot::South => 'v',
+        }
+    }
+}
+
+/// Both herds as bitboards: every row is a run of u64 words, column `c`
+/// being bit `c % 64` of word `c / 64`, so a whole herd moves a row at a
+/// time with shifts and masks instead of one sea cucumber at a time.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Herds {
+    width: usize,
+    height: usize,
+    /// Words per row
+    words: usize,
+    east: Vec<u64>,
+    south: Vec<u64>,
+}
+
+impl Herds {
+    pub fn new(grid: &Grid<Spot>) -> Self {
+        let (width, height) = (grid.width(), grid.height());
+        let words = width.div_ceil(64);
+        let mut herds = Herds {
+            width,
+            height,
+            words,
+            east: vec![0; words * height],
+            south: vec![0; words * height],
+        };
+        for (row, col) in grid.positions() {
+            let bit = 1 << (col % 64);
+            match grid[(row, col)] {
+                Spot::East => herds.east[row * words + col / 64] |= bit,
+                Spot::South => herds.south[row * words + col / 64] |= bit,
+                Spot::Empty => (),
+            }
+        }
+        herds
+    }
+
+    pub fn to_grid(&self) -> Grid<Spot> {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            let (word, bit) = (row * self.words + col / 64, col % 64);
+            if self.east[word] >> bit & 1 == 1 {
+                Spot::East
+            } else if self.south[word] >> bit & 1 == 1 {
+                Spot::South
+            } else {
+                Spot::Empty
+            }
+        })
+    }
+
+    /// Moves the east herd and then the south one, returning whether anyone
+    /// moved at all
+    pub fn step(&mut self) -> bool {
+        let east = self.step_east();
+        let south = self.step_south();
+        east || south
+    }
+
+    fn step_east(&mut self) -> bool {
+        let mut moved = false;
+        let mut occupied = vec![0; self.words];
+        let mut blocked = vec![0; self.words];
+        let mut moving = vec![0; self.words];
+        let mut arrived = vec![0; self.words];
+        for (east, south) in self.east.chunks_mut(self.words).zip(self.south.chunks(self.words)) {
+            for ((o, e), s) in occupied.iter_mut().zip(&*east).zip(south) {
+                *o = e | s;
+            }
+            // Whether the spot to the east of each one is taken
+            rotate_west(self.width, &occupied, &mut blocked);
+            for ((m, e), b) in moving.iter_mut().zip(&*east).zip(&blocked) {
+                *m = e & !b;
+            }
+            rotate_east(self.width, &moving, &mut arrived);
+            for ((e, m), a) in east.iter_mut().zip(&moving).zip(&arrived) {
+                moved |= *m != 0;
+                *e = *e & !m | a;
+            }
+        }
+        moved
+    }
+
+    fn step_south(&mut self) -> bool {
+        let (words, height) = (self.words, self.height);
+        // Who can go has to be worked out before anyone does, or the ones at
+        // the bottom would see the top row already moved
+        let moving: Vec<u64> = (0..height * words)
+            .map(|i| {
+                let below = (i + words) % (height * words);
+                self.south[i] & !(self.east[below] | self.south[below])
+            })
+            .collect();
+        for i in 0..height * words {
+            let above = (i + (height - 1) * words) % (height * words);
+            self.south[i] = self.south[i] & !moving[i] | moving[above];
+        }
+        moving.iter().any(|&m| m != 0)
+    }
+}
+
+/// Moves every bit of a row `width` columns wide one column east, the last
+/// column wrapping round to the first
+fn rotate_east(width: usize, row: &[u64], out: &mut [u64]) {
+    let words = row.len();
+    let mut carry = row[words - 1] >> ((width - 1) % 64) & 1;
+    for (out, word) in out.iter_mut().zip(row) {
+        *out = word << 1 | carry;
+        carry = word >> 63;
+    }
+    // Whatever got pushed past the last column went round already
+    if !width.is_multiple_of(64) {
+        out[words - 1] &= (1 << (width % 64)) - 1;
+    }
+}
+
+/// Moves every bit of the row one column west, the first column wrapping
+/// round to the last
+fn rotate_west(width: usize, row: &[u64], out: &mut [u64]) {
+    let mut carry = 0;
+    for (out, word) in out.iter_mut().zip(row).rev() {
+        *out = word >> 1 | carry << 63;
+        carry = word & 1;
+    }
+    out[row.len() - 1] |= carry << ((width - 1) % 64);
+}
+
+impl Display for Herds {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.to_grid())
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Herds;
+    type Error = grid::ParseError;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+    const DAY: u8 = 25;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Herds, grid::ParseError> {
+        Ok(Herds::new(&input.parse()?))
+    }
+
+    /// The first step on which nobody moves
+    fn part1(herds: &Herds) -> usize {
+        let mut herds = herds.clone();
+        let mut steps = 1;
+        while herds.step() {
+            steps += 1;
+        }
+        common::debug!(2, "{herds}");
+        steps
+    }
+
+    /// Day 25 only has the one part
+    fn part2(_: &Herds) -> &'static str {
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One sea cucumber at a time, the way the puzzle describes it
+    fn slow_step(grid: &Grid<Spot>) -> Grid<Spot> {
+        let (width, height) = (grid.width(), grid.height());
+        let mut east = grid.clone();
+        for (row, col) in grid.positions() {
+            let next = (row, (col + 1) % width);
+            if grid[(row, col)] == Spot::East && grid[next] == Spot::Empty {
+                east[(row, col)] = Spot::Empty;
+                east[next] = Spot::East;
+            }
+        }
+        let mut south = east.clone();
+        for (row, col) in east.positions() {
+            let next = ((row + 1) % height, col);
+            if east[(row, col)] == Spot::South && east[next] == Spot::Empty {
+                south[(row, col)] = Spot::Empty;
+                south[next] = Spot::South;
+            }
+        }
+        south
+    }
+
+    #[test]
+    fn one_row() {
+        let mut herds = Day25::parse("...>>>>>...").unwrap();
+        herds.step();
+        assert_eq!(herds.to_string(), "...>>>>.>..");
+        herds.step();
+        assert_eq!(herds.to_string(), "...>>>.>.>.");
+    }
+
+    #[test]
+    fn wrapping() {
+        let mut herds = Day25::parse("...>...\n.......\n......>\nv.....>\n......>\n.......\n..vvv..").unwrap();
+        herds.step();
+        assert_eq!(herds.to_string(), "..vv>..\n.......\n>......\nv.....>\n>......\n.......\n....v..");
+    }
+
+    #[test]
+    fn example() {
+        let herds = Day25::parse(Day25::EXAMPLE.unwrap()).unwrap();
+        assert_eq!(Day25::part1(&herds), 58);
+    }
+
+    #[test]
+    fn wide_grids() {
+        // Wider than a word, and not a multiple of one, so carries between
+        // words and the wrap from the last one both get used
+        for width in [64, 130, 200] {
+            let mut grid = Grid::from_fn(width, 37, |(row, col)| match (row * 7919 + col * 104729) % 7 {
+                0 | 1 => Spot::East,
+                2 | 3 => Spot::South,
+                _ => Spot::Empty,
+            });
+            let mut herds = Herds::new(&grid);
+            for _ in 0..50 {
+                grid = slow_step(&grid);
+                herds.step();
+                assert_eq!(herds.to_grid(), grid);
+            }
+        }
+    }
+}
@@ -1,3 +1,7 @@
-fn main() {
-    println!("Hello, world!");
+use std::process::ExitCode;
+
+use day_25::Day25;
+
+fn main() -> ExitCode {
+    common::main::<Day25>()
 }
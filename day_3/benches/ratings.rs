@@ -0,0 +1,46 @@
+//! Finding both life support ratings of a generated million-line report,
+//! by filtering the list of numbers over and over and by walking a trie.
+//! Run with `cargo bench -p day_3`, optionally followed by `-- LINES WIDTH`.
+use std::time::{Duration, Instant};
+
+use day_3::{trie::Trie, Keep, Report, TieBreak};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const RUNS: usize = 5;
+
+fn main() {
+    // `cargo bench` passes `--bench` along, which isn't a number
+    let args: Vec<usize> = std::env::args().skip(1).filter_map(|arg| arg.parse().ok()).collect();
+    let lines = args.first().copied().unwrap_or(1_000_000);
+    let width = args.get(1).copied().unwrap_or(32) as u32;
+
+    let mut rng = StdRng::seed_from_u64(3);
+    let numbers = (0..lines).map(|_| rng.gen::<u128>() >> (128 - width)).collect();
+    let report = Report { width, numbers };
+    println!("{lines} numbers, {width} bits wide, best and median of {RUNS} runs");
+
+    let filtered = time("filtering", || {
+        (report.rating(Keep::MostCommon, TieBreak::Ones), report.rating(Keep::LeastCommon, TieBreak::Zeros))
+    });
+    let mut trie = None;
+    time("trie build", || trie = Some(Trie::new(&report)));
+    let trie = trie.unwrap();
+    let walked = time("trie walk", || {
+        (trie.rating(Keep::MostCommon, TieBreak::Ones).unwrap(), trie.rating(Keep::LeastCommon, TieBreak::Zeros).unwrap())
+    });
+    assert_eq!(filtered, walked, "the trie found different ratings");
+}
+
+/// Runs `f` a few times, printing how long it took
+fn time<T>(name: &str, mut f: impl FnMut() -> T) -> T {
+    let mut times: Vec<Duration> = Vec::new();
+    let mut result = None;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        result = Some(f());
+        times.push(start.elapsed());
+    }
+    times.sort();
+    println!("{name:<12} {:>12?} {:>12?}", times[0], times[RUNS / 2]);
+    result.unwrap()
+}
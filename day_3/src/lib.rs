@@ -0,0 +1,236 @@
+pub mod trie;
+
+use std::{cmp::Ordering, fmt::{Display, Formatter}};
+use common::{big::BigUint, Position, Solution};
+use crate::trie::Trie;
+
+/// The widest report that fits: every number is kept in a `u128`
+pub const MAX_WIDTH: u32 = u128::BITS;
+
+/// The diagnostic report, one number per line, all `width` bits wide
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    pub width: u32,
+    pub numbers: Vec<u128>,
+}
+
+/// What can go wrong reading the diagnostic report
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Every line is made of `0`s and `1`s
+    NotBinary { at: Position, found: char },
+    /// Every line is as wide as the first
+    UnevenWidth { line: usize, expected: u32, found: usize },
+    /// Wider than a number can hold
+    TooWide { line: usize, found: usize },
+    /// There has to be something to find the most common bits of
+    Empty,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotBinary { at, found } => write!(f, "{at}: expected `0` or `1`, found `{found}`"),
+            ParseError::UnevenWidth { line, expected, found } => {
+                write!(f, "line {line}: expected {expected} bits like the first line, found {found}")
+            }
+            ParseError::TooWide { line, found } => {
+                write!(f, "line {line}: expected at most {MAX_WIDTH} bits, found {found}")
+            }
+            ParseError::Empty => write!(f, "the report is empty"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Report {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut numbers = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let bits = line.trim();
+            if bits.is_empty() {
+                continue;
+            }
+            let found = bits.chars().count();
+            match width {
+                None if found > MAX_WIDTH as usize => return Err(ParseError::TooWide { line: i + 1, found }),
+                None => width = Some(found as u32),
+                Some(expected) if found != expected as usize => {
+                    return Err(ParseError::UnevenWidth { line: i + 1, expected, found })
+                }
+                Some(_) => (),
+            }
+            let mut number = 0;
+            for (offset, c) in bits.char_indices() {
+                let bit = match c {
+                    '0' => 0,
+                    '1' => 1,
+                    _ => return Err(ParseError::NotBinary { at: Position::of(i, line, &bits[offset..]), found: c }),
+                };
+                number = number << 1 | bit;
+            }
+            numbers.push(number);
+        }
+        match width {
+            Some(width) => Ok(Report { width, numbers }),
+            None => Err(ParseError::Empty),
+        }
+    }
+
+    /// The masks of each bit, most significant first
+    pub fn bits(&self) -> impl Iterator<Item = u128> {
+        (0..self.width).rev().map(|bit| 1 << bit)
+    }
+
+    /// Every bit that is a 1 in at least half the numbers
+    pub fn gamma(&self) -> u128 {
+        self.bits()
+            .filter(|&mask| ones(&self.numbers, mask) * 2 >= self.numbers.len())
+            .fold(0, |gamma, mask| gamma | mask)
+    }
+
+    /// Every bit that is a 1 in less than half of them: gamma's opposite
+    pub fn epsilon(&self) -> u128 {
+        !self.gamma() & self.mask()
+    }
+
+    /// All `width` bits set
+    fn mask(&self) -> u128 {
+        u128::MAX >> (MAX_WIDTH - self.width)
+    }
+
+    /// The rating found by keeping only the numbers with the `keep` bit
+    /// at each position in turn until just one is left. This goes over all
+    /// the numbers still left for every bit; [`trie::Trie`] doesn't.
+    pub fn rating(&self, keep: Keep, tie: TieBreak) -> u128 {
+        let mut left = self.numbers.clone();
+        for mask in self.bits() {
+            if left.len() == 1 {
+                break;
+            }
+            let ones = ones(&left, mask);
+            let keep_ones = keep.side(left.len() - ones, ones, tie) == 1;
+            left.retain(|&n| (n & mask != 0) == keep_ones);
+        }
+        // Whatever is left is the same number over and over
+        left[0]
+    }
+}
+
+/// Which bit a rating keeps the numbers with at each position
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Keep {
+    /// Like the oxygen generator rating
+    MostCommon,
+    /// Like the CO2 scrubber rating
+    LeastCommon,
+}
+
+/// Which bit wins when there are as many 0s as 1s
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TieBreak {
+    Zeros,
+    Ones,
+}
+
+impl Keep {
+    /// The side to keep, 0 or 1, given how many numbers have each. A side
+    /// without any numbers is never picked, even as the least common: the
+    /// puzzle never says what happens then, and it would leave nothing.
+    pub fn side(self, zeros: usize, ones: usize, tie: TieBreak) -> usize {
+        if zeros == 0 || ones == 0 {
+            return (ones > 0) as usize;
+        }
+        let ones_win = match zeros.cmp(&ones) {
+            Ordering::Equal => {
+                return match tie {
+                    TieBreak::Zeros => 0,
+                    TieBreak::Ones => 1,
+                }
+            }
+            Ordering::Less => true,
+            Ordering::Greater => false,
+        };
+        (ones_win == (self == Keep::MostCommon)) as usize
+    }
+}
+
+/// How many of `numbers` have the bit in `mask` set
+fn ones(numbers: &[u128], mask: u128) -> usize {
+    numbers.iter().filter(|&&n| n & mask != 0).count()
+}
+
+/// `a * b`, which for reports wider than 64 bits can be too big for a u128
+/// and gets worked out as a [`BigUint`] instead
+pub fn product(a: u128, b: u128) -> String {
+    match a.checked_mul(b) {
+        Some(product) => product.to_string(),
+        None => (&BigUint::from(a) * &BigUint::from(b)).to_string(),
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Report;
+    type Error = ParseError;
+    type Answer1 = String;
+    type Answer2 = String;
+    const DAY: u8 = 3;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Report, ParseError> {
+        Report::parse(input)
+    }
+
+    fn part1(report: &Report) -> String {
+        product(report.gamma(), report.epsilon())
+    }
+
+    fn part2(report: &Report) -> String {
+        let trie = Trie::new(report);
+        let oxygen = trie.rating(Keep::MostCommon, TieBreak::Ones).expect("parse checked the report isn't empty");
+        let co2 = trie.rating(Keep::LeastCommon, TieBreak::Zeros).expect("parse checked the report isn't empty");
+        product(oxygen, co2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let report = Day3::parse(Day3::EXAMPLE.unwrap()).unwrap();
+        assert_eq!(report.width, 5);
+        assert_eq!((report.gamma(), report.epsilon()), (22, 9));
+        assert_eq!((report.rating(Keep::MostCommon, TieBreak::Ones), report.rating(Keep::LeastCommon, TieBreak::Zeros)), (23, 10));
+    }
+
+    #[test]
+    fn widths() {
+        let report = Report::parse("1\n0\n1").unwrap();
+        assert_eq!((report.gamma(), report.epsilon()), (1, 0));
+        let wide = format!("{}\n{}\n", "1".repeat(128), "0".repeat(128));
+        let report = Report::parse(&wide).unwrap();
+        assert_eq!(report.gamma(), u128::MAX);
+        assert_eq!(report.rating(Keep::LeastCommon, TieBreak::Zeros), 0);
+        assert_eq!(
+            Report::parse("101\n1011"),
+            Err(ParseError::UnevenWidth { line: 2, expected: 3, found: 4 })
+        );
+        assert_eq!(Report::parse(&"1".repeat(129)), Err(ParseError::TooWide { line: 1, found: 129 }));
+        assert!(matches!(Report::parse("10\n12"), Err(ParseError::NotBinary { found: '2', .. })));
+        assert_eq!(Report::parse("\n"), Err(ParseError::Empty));
+    }
+
+    #[test]
+    fn products() {
+        assert_eq!(product(22, 9), "198");
+        assert_eq!(product(u128::MAX, 1), u128::MAX.to_string());
+        assert_eq!(product(u128::MAX, u128::MAX), "115792089237316195423570985008687907852589419931798687112530834793049593217025");
+        assert_eq!(product(1 << 64, 1 << 64), "340282366920938463463374607431768211456");
+    }
+}
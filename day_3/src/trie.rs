@@ -0,0 +1,138 @@
+//! The report as a binary trie, most significant bit at the root, with
+//! every node knowing how many numbers are under it. Filtering by the bit
+//! criteria is then just walking down it, one bit per level, rather than
+//! going over every number left once for every bit.
+use crate::{Keep, Report, TieBreak};
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Node {
+    /// How many numbers are under this node, counting repeats
+    count: u32,
+    /// The nodes for a 0 and a 1 next, 0 meaning there is none since the
+    /// root is nobody's child
+    children: [u32; 2],
+    /// Where in [`Trie::numbers`] the only number under the node is while
+    /// `count` is 1. Such a node has no children yet: they only get made
+    /// once a second number turns up.
+    number: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct Trie {
+    width: u32,
+    /// The report's numbers, in order
+    numbers: Vec<u128>,
+    nodes: Vec<Node>,
+}
+
+impl Trie {
+    pub fn new(report: &Report) -> Self {
+        // In order, each number's path mostly goes through nodes the last
+        // one just touched, which is a lot kinder to the cache
+        let mut numbers = report.numbers.clone();
+        numbers.sort_unstable();
+        let mut trie = Trie { width: report.width, numbers, nodes: vec![Node::default()] };
+        for i in 0..trie.numbers.len() {
+            trie.insert(i as u32);
+        }
+        trie
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes[0].count as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds the `index`th number
+    fn insert(&mut self, index: u32) {
+        let number = self.numbers[index as usize];
+        let mut node = 0;
+        for bit in (0..self.width).rev() {
+            let count = self.nodes[node].count;
+            self.nodes[node].count += 1;
+            if count == 0 {
+                self.nodes[node].number = index;
+                return;
+            }
+            if count == 1 && self.nodes[node].children == [0, 0] {
+                // The number that had this node to itself moves down a level
+                let only = self.nodes[node].number;
+                let child = self.add(Node { count: 1, children: [0, 0], number: only });
+                let side = self.numbers[only as usize] >> bit & 1;
+                self.nodes[node].children[side as usize] = child;
+            }
+            let side = (number >> bit & 1) as usize;
+            if self.nodes[node].children[side] == 0 {
+                let child = self.add(Node::default());
+                self.nodes[node].children[side] = child;
+            }
+            node = self.nodes[node].children[side] as usize;
+        }
+        // Out of bits, so the path here spells out the number. The first one
+        // to get here keeps the node like anywhere else, and any more are
+        // repeats of it.
+        if self.nodes[node].count == 0 {
+            self.nodes[node].number = index;
+        }
+        self.nodes[node].count += 1;
+    }
+
+    fn add(&mut self, node: Node) -> u32 {
+        self.nodes.push(node);
+        (self.nodes.len() - 1) as u32
+    }
+
+    /// The number left after keeping the numbers with the `keep` bit at
+    /// each position in turn, or `None` for an empty report
+    pub fn rating(&self, keep: Keep, tie: TieBreak) -> Option<u128> {
+        let mut node = &self.nodes[0];
+        if node.count == 0 {
+            return None;
+        }
+        let mut rating = 0;
+        for bit in (0..self.width).rev() {
+            if node.count == 1 {
+                return Some(self.numbers[node.number as usize]);
+            }
+            let [zeros, ones] = node.children.map(|child| match child {
+                0 => 0,
+                child => self.nodes[child as usize].count as usize,
+            });
+            let side = keep.side(zeros, ones, tie);
+            rating |= (side as u128) << bit;
+            node = &self.nodes[node.children[side] as usize];
+        }
+        Some(rating)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day3;
+    use common::Solution;
+
+    #[test]
+    fn matches_filtering() {
+        let example = Day3::parse(Day3::EXAMPLE.unwrap()).unwrap();
+        let repeats = Report::parse("000\n000\n011\n011\n010\n111").unwrap();
+        let skewed = Report::parse("1100\n1101\n1110").unwrap();
+        for report in [example, repeats, skewed] {
+            let trie = Trie::new(&report);
+            assert_eq!(trie.len(), report.numbers.len());
+            for keep in [Keep::MostCommon, Keep::LeastCommon] {
+                for tie in [TieBreak::Ones, TieBreak::Zeros] {
+                    assert_eq!(trie.rating(keep, tie), Some(report.rating(keep, tie)), "{report:?} {keep:?} {tie:?}");
+                }
+            }
+        }
+        let example = Day3::parse(Day3::EXAMPLE.unwrap()).unwrap();
+        let trie = Trie::new(&example);
+        assert_eq!(trie.rating(Keep::MostCommon, TieBreak::Ones), Some(23));
+        assert_eq!(trie.rating(Keep::LeastCommon, TieBreak::Zeros), Some(10));
+        assert_eq!(trie.rating(Keep::LeastCommon, TieBreak::Ones), Some(15));
+    }
+}
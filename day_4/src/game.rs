@@ -0,0 +1,160 @@
+//! Plays every board at once. Each number knows where it is on every
+//! board, and each line counts how many of its squares have been hit, so a
+//! call only touches the squares it marks rather than checking every line
+//! of every board again.
+use std::{collections::HashMap, fmt::{Display, Formatter}};
+
+use crate::{Bingo, Rules};
+
+/// A board getting its first complete line
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Win {
+    /// Which board, counting from 0 in the order of the input
+    pub board: usize,
+    /// The number that completed the line
+    pub call: usize,
+    /// How many numbers had been called by then, counting this one
+    pub turn: usize,
+    /// The sum of the unmarked squares times the call
+    pub score: usize,
+}
+
+impl Display for Win {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "turn {}: board {} wins on {} with a score of {}",
+            self.turn, self.board, self.call, self.score
+        )
+    }
+}
+
+/// Which lines every square of a board of some size is on, under some rules
+struct Layout {
+    /// How many squares each line has
+    lengths: Vec<u32>,
+    /// The lines each square is on, by square
+    lines: Vec<Vec<usize>>,
+}
+
+impl Layout {
+    fn new(size: usize, rules: Rules) -> Self {
+        let board_lines = crate::Board { size, squares: vec![0; size * size] }.lines(rules);
+        let mut lines = vec![Vec::new(); size * size];
+        for (line, squares) in board_lines.iter().enumerate() {
+            for &square in squares {
+                lines[square].push(line);
+            }
+        }
+        Layout { lengths: board_lines.iter().map(|l| l.len() as u32).collect(), lines }
+    }
+}
+
+/// Where a number is: the board, row and column
+type Spot = (usize, usize, usize);
+
+/// Every board win, in the order they happen. Boards that win on the same
+/// call come in the order of the input, and a board only wins once.
+pub fn play(bingo: &Bingo, rules: Rules) -> Vec<Win> {
+    let mut layouts: HashMap<usize, Layout> = HashMap::new();
+    let mut spots: HashMap<usize, Vec<Spot>> = HashMap::new();
+    for (b, board) in bingo.boards.iter().enumerate() {
+        layouts.entry(board.size).or_insert_with(|| Layout::new(board.size, rules));
+        for (i, &number) in board.squares.iter().enumerate() {
+            spots.entry(number).or_default().push((b, i / board.size, i % board.size));
+        }
+    }
+
+    let mut hits: Vec<Vec<u32>> = bingo.boards.iter().map(|b| vec![0; layouts[&b.size].lengths.len()]).collect();
+    let mut marked: Vec<Vec<bool>> = bingo.boards.iter().map(|b| vec![false; b.squares.len()]).collect();
+    let mut unmarked: Vec<usize> = bingo.boards.iter().map(|b| b.squares.iter().sum()).collect();
+    let mut won = vec![false; bingo.boards.len()];
+
+    let mut wins = Vec::new();
+    for (turn, &call) in bingo.calls.iter().enumerate() {
+        let Some(spots) = spots.get(&call) else { continue };
+        let mut winners = Vec::new();
+        for &(b, row, col) in spots {
+            let size = bingo.boards[b].size;
+            let square = row * size + col;
+            if marked[b][square] {
+                continue; // called before
+            }
+            marked[b][square] = true;
+            unmarked[b] -= call;
+            let layout = &layouts[&size];
+            for &line in &layout.lines[square] {
+                hits[b][line] += 1;
+                if hits[b][line] == layout.lengths[line] && !won[b] {
+                    won[b] = true;
+                    winners.push(b);
+                }
+            }
+        }
+        // Only now, so that every square the call marks is left out of the score
+        winners.sort_unstable();
+        wins.extend(winners.into_iter().map(|b| Win { board: b, call, turn: turn + 1, score: unmarked[b] * call }));
+    }
+    wins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Board, Day4};
+    use common::Solution;
+    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+    use std::collections::HashSet;
+
+    /// Checking every line of every board after every call
+    fn slow_play(bingo: &Bingo, rules: Rules) -> Vec<Win> {
+        let mut called = HashSet::new();
+        let mut won = vec![false; bingo.boards.len()];
+        let lines: Vec<_> = bingo.boards.iter().map(|board| board.lines(rules)).collect();
+        let mut wins = Vec::new();
+        for (turn, &call) in bingo.calls.iter().enumerate() {
+            called.insert(call);
+            for (b, board) in bingo.boards.iter().enumerate() {
+                let done = lines[b].iter().any(|l| l.iter().all(|&i| called.contains(&board.squares[i])));
+                if done && !won[b] {
+                    won[b] = true;
+                    let unmarked: usize = board.squares.iter().filter(|n| !called.contains(n)).sum();
+                    wins.push(Win { board: b, call, turn: turn + 1, score: unmarked * call });
+                }
+            }
+        }
+        wins
+    }
+
+    #[test]
+    fn example() {
+        let bingo = Day4::parse(Day4::EXAMPLE.unwrap()).unwrap();
+        let wins = play(&bingo, Rules::default());
+        assert_eq!(wins[0], Win { board: 2, call: 24, turn: 12, score: 4512 });
+        assert_eq!(wins.last(), Some(&Win { board: 1, call: 13, turn: 15, score: 1924 }));
+        assert_eq!(wins.len(), 3);
+    }
+
+    #[test]
+    fn thousands_of_boards() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let mut shuffled = |n: usize| {
+            let mut numbers: Vec<usize> = (0..n).collect();
+            numbers.shuffle(&mut rng);
+            numbers
+        };
+        let calls = shuffled(100);
+        let boards: Vec<Board> = (0..3000)
+            .map(|b| {
+                let size = 3 + b % 4;
+                Board { size, squares: shuffled(100)[..size * size].to_vec() }
+            })
+            .collect();
+        let bingo = Bingo { calls, boards };
+        for rules in [Rules::default(), "diagonals,full".parse().unwrap()] {
+            let wins = play(&bingo, rules);
+            assert_eq!(wins.len(), 3000);
+            assert_eq!(wins, slow_play(&bingo, rules));
+        }
+    }
+}
@@ -0,0 +1,249 @@
+pub mod game;
+
+use std::{fmt::{Display, Formatter}, str::FromStr};
+use common::{Position, Solution};
+use crate::game::play;
+
+/// A square bingo card, its numbers row by row
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Board {
+    size: usize,
+    squares: Vec<usize>,
+}
+
+impl Board {
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> usize {
+        self.squares[row * self.size + col]
+    }
+
+    pub fn squares(&self) -> &[usize] {
+        &self.squares
+    }
+
+    /// Every line that wins under `rules`, as the indices of its squares
+    /// in [`Board::squares`]
+    pub fn lines(&self, rules: Rules) -> Vec<Vec<usize>> {
+        let n = self.size;
+        let mut lines = Vec::new();
+        if rules.rows {
+            lines.extend((0..n).map(|row| (0..n).map(|col| row * n + col).collect()));
+        }
+        if rules.columns {
+            lines.extend((0..n).map(|col| (0..n).map(|row| row * n + col).collect()));
+        }
+        if rules.diagonals {
+            lines.push((0..n).map(|i| i * n + i).collect());
+            lines.push((0..n).map(|i| i * n + n - 1 - i).collect());
+        }
+        if rules.full_card {
+            lines.push((0..n * n).collect());
+        }
+        lines
+    }
+}
+
+/// Which lines of a board win. The puzzle only counts rows and columns.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rules {
+    pub rows: bool,
+    pub columns: bool,
+    /// Both corner to corner lines
+    pub diagonals: bool,
+    /// Every square of the board
+    pub full_card: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules { rows: true, columns: true, diagonals: false, full_card: false }
+    }
+}
+
+impl FromStr for Rules {
+    type Err = String;
+
+    /// A comma separated list of `rows`, `columns`, `diagonals` and `full`
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut rules = Rules { rows: false, columns: false, diagonals: false, full_card: false };
+        for rule in s.split(',').map(str::trim) {
+            match rule {
+                "rows" => rules.rows = true,
+                "columns" => rules.columns = true,
+                "diagonals" => rules.diagonals = true,
+                "full" => rules.full_card = true,
+                _ => return Err(format!("expected `rows`, `columns`, `diagonals` or `full`, found `{rule}`")),
+            }
+        }
+        Ok(rules)
+    }
+}
+
+pub struct Bingo {
+    pub calls: Vec<usize>,
+    pub boards: Vec<Board>,
+}
+
+/// What can go wrong reading the bingo subsystem
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The first line should be the comma separated numbers to call
+    NoCalls,
+    /// Calls and board squares are all numbers
+    NotANumber { at: Position, found: String },
+    /// Every row of a board is as long as its first row
+    RaggedRow { line: usize, expected: usize, found: usize },
+    /// A board has as many rows as its rows have numbers
+    UnfinishedBoard { line: usize, expected: usize, rows: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NoCalls => write!(f, "expected a line of numbers to call, found nothing"),
+            ParseError::NotANumber { at, found } => {
+                write!(f, "{at}: expected a number, found `{found}`")
+            }
+            ParseError::RaggedRow { line, expected, found } => {
+                write!(f, "line {line}: expected {expected} numbers in a board row, found {found}")
+            }
+            ParseError::UnfinishedBoard { line, expected, rows } => {
+                write!(f, "line {line}: expected {expected} rows in the board, found {rows}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Bingo;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 4;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(raw: &str) -> Result<Bingo, ParseError> {
+        let input: Vec<&str> = raw
+            .trim_end()
+            .lines()
+            .collect();
+
+        let calls: &str = input.first().ok_or(ParseError::NoCalls)?;
+        let calls: Vec<usize> = calls
+            .split(',')
+            .map(|x| parse_number(0, calls, x.trim()))
+            .collect::<Result<_, _>>()?;
+
+        let boards: Vec<Board> = build_boards(&input[1..])?;
+
+        Ok(Bingo { calls, boards })
+    }
+
+    /// The score of the first board to win, or 0 if none ever does
+    fn part1(bingo: &Bingo) -> usize {
+        play(bingo, Rules::default()).first().map_or(0, |win| win.score)
+    }
+
+    /// The score of the last board to win, or 0 if none ever does
+    fn part2(bingo: &Bingo) -> usize {
+        let wins = play(bingo, Rules::default());
+        for win in &wins {
+            common::debug!(1, "{win}");
+        }
+        wins.last().map_or(0, |win| win.score)
+    }
+}
+
+fn parse_number(index: usize, line: &str, x: &str) -> Result<usize, ParseError> {
+    x.parse::<usize>().map_err(|_| ParseError::NotANumber {
+        at: Position::of(index, line, x),
+        found: x.to_string(),
+    })
+}
+
+/// The boards start on the second line of the input. Each one is as big as
+/// its first row is long, so a board of 5 numbers a row has 5 rows.
+fn build_boards(board_str: &[&str]) -> Result<Vec<Board>, ParseError> {
+    let mut boards: Vec<Board> = Vec::new();
+    let mut board: Option<Board> = None;
+
+    for (index, line) in board_str.iter().enumerate().map(|(index, line)| (index + 1, line)) {
+        if line.trim().is_empty() {
+            if let Some(b) = board.take() {
+                let rows = b.squares.len() / b.size;
+                return Err(ParseError::UnfinishedBoard { line: index + 1, expected: b.size, rows });
+            }
+            continue;
+        }
+
+        let row: Vec<_> = line
+            .split_whitespace()
+            .map(|x| parse_number(index, line, x))
+            .collect::<Result<_, _>>()?;
+        let b = board.get_or_insert_with(|| Board { size: row.len(), squares: Vec::new() });
+        if row.len() != b.size {
+            return Err(ParseError::RaggedRow { line: index + 1, expected: b.size, found: row.len() });
+        }
+        b.squares.extend(row);
+
+        if b.squares.len() == b.size * b.size {
+            boards.extend(board.take());
+        }
+    }
+
+    if let Some(b) = board {
+        return Err(ParseError::UnfinishedBoard {
+            line: board_str.len() + 1,
+            expected: b.size,
+            rows: b.squares.len() / b.size,
+        });
+    }
+    Ok(boards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn board_sizes() {
+        let bingo = Day4::parse("1,2,3,4\n\n1 2\n3 4\n\n4 3\n2 1\n5 6\n7 8").unwrap();
+        assert_eq!(bingo.boards.len(), 3);
+        assert_eq!(bingo.boards[1].get(1, 0), 2);
+        assert!(bingo.boards.iter().all(|b| b.size() == 2));
+        let bingo = Day4::parse("9\n\n1 2 3\n4 5 6\n7 8 9").unwrap();
+        assert_eq!(bingo.boards[0].size(), 3);
+
+        assert_eq!(
+            Day4::parse("1\n\n1 2\n3").err(),
+            Some(ParseError::RaggedRow { line: 4, expected: 2, found: 1 })
+        );
+        assert_eq!(
+            Day4::parse("1\n\n1 2 3\n4 5 6\n\n1 2 3").err(),
+            Some(ParseError::UnfinishedBoard { line: 5, expected: 3, rows: 2 })
+        );
+        assert_eq!(
+            Day4::parse("1\n\n1 2 3\n4 5 6").err(),
+            Some(ParseError::UnfinishedBoard { line: 4, expected: 3, rows: 2 })
+        );
+    }
+
+    #[test]
+    fn rules() {
+        let bingo = Day4::parse("1,5,9,3,7\n\n1 2 3\n4 5 6\n7 8 9").unwrap();
+        assert_eq!(play(&bingo, Rules::default()), vec![]);
+        let diagonals = "diagonals".parse().unwrap();
+        // 1, 5 and 9 make a diagonal, leaving 2+3+4+6+7+8
+        assert_eq!(play(&bingo, diagonals)[0].score, 30 * 9);
+        let full: Rules = "rows,full".parse().unwrap();
+        assert_eq!(play(&bingo, full), vec![]);
+        assert!("rows,corners".parse::<Rules>().is_err());
+    }
+}
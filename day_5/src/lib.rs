@@ -0,0 +1,164 @@
+pub mod render;
+pub mod sweep;
+
+use std::{collections::HashMap, fmt::{Display, Formatter}};
+use common::{Position, Solution};
+
+pub type Point = (i64, i64);
+
+/// A line of vents, both ends included. It is horizontal, vertical or at
+/// exactly 45 degrees, which parsing checks.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Line {
+    /// Lines along the x or y axis, which is all part 1 looks at. A line
+    /// that is just one point counts as one of them.
+    pub fn is_axis_aligned(&self) -> bool {
+        self.start.0 == self.end.0 || self.start.1 == self.end.1
+    }
+
+    /// How far each point is from the last one
+    pub fn step(&self) -> Point {
+        ((self.end.0 - self.start.0).signum(), (self.end.1 - self.start.1).signum())
+    }
+
+    /// How many points the line covers
+    pub fn length(&self) -> u64 {
+        self.start.0.abs_diff(self.end.0).max(self.start.1.abs_diff(self.end.1)) + 1
+    }
+
+    /// Every point the line covers, from start to end. Both parts draw their
+    /// lines with this.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let ((x, y), (dx, dy)) = (self.start, self.step());
+        (0..self.length() as i64).map(move |i| (x + i * dx, y + i * dy))
+    }
+}
+
+/// What can go wrong reading the lines of vents
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Every line looks like `x1,y1 -> x2,y2`
+    NoArrow { line: usize },
+    /// Both ends of a line are an `x,y` pair
+    NotAPoint { at: Position, found: String },
+    /// Coordinates are whole numbers
+    NotACoordinate { at: Position, found: String },
+    /// Lines go straight across, straight down or at 45 degrees
+    Slanted { line: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NoArrow { line } => {
+                write!(f, "line {line}: expected `x1,y1 -> x2,y2`, found no ` -> `")
+            }
+            ParseError::NotAPoint { at, found } => {
+                write!(f, "{at}: expected a point like `x,y`, found `{found}`")
+            }
+            ParseError::NotACoordinate { at, found } => {
+                write!(f, "{at}: expected a whole number, found `{found}`")
+            }
+            ParseError::Slanted { line } => {
+                write!(f, "line {line}: expected a horizontal, vertical or 45 degree line")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Line>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 5;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+        let mut lines: Vec<Line> = Vec::new();
+        for (i, line) in input.trim_end().lines().enumerate() {
+            let (start, end) = line
+                .split_once(" -> ")
+                .ok_or(ParseError::NoArrow { line: i + 1 })?;
+            let start = parse_point(i, line, start.trim())?;
+            let end = parse_point(i, line, end.trim())?;
+            let vent = Line { start, end };
+            if !vent.is_axis_aligned() && start.0.abs_diff(end.0) != start.1.abs_diff(end.1) {
+                return Err(ParseError::Slanted { line: i + 1 });
+            }
+            lines.push(vent);
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Vec<Line>) -> usize {
+        overlaps(&coverage(lines.iter().filter(|line| line.is_axis_aligned())))
+    }
+
+    fn part2(lines: &Vec<Line>) -> usize {
+        overlaps(&coverage(lines))
+    }
+}
+
+fn parse_point(index: usize, line: &str, point: &str) -> Result<Point, ParseError> {
+    let (x, y) = point.split_once(',').ok_or_else(|| ParseError::NotAPoint {
+        at: Position::of(index, line, point),
+        found: point.to_string(),
+    })?;
+    let coordinate = |c: &str| {
+        c.parse::<i64>().map_err(|_| ParseError::NotACoordinate {
+            at: Position::of(index, line, c),
+            found: c.to_string(),
+        })
+    };
+    Ok((coordinate(x)?, coordinate(y)?))
+}
+
+/// How many of the lines cover each point, leaving out the points none do.
+/// Only the points lines actually cover get stored, so it doesn't matter
+/// how far apart they are or which side of 0.
+pub fn coverage<'a>(lines: impl IntoIterator<Item = &'a Line>) -> HashMap<Point, u32> {
+    let mut map = HashMap::new();
+    for line in lines {
+        for point in line.points() {
+            *map.entry(point).or_insert(0) += 1;
+        }
+    }
+    common::debug!(2, "{} points covered", map.len());
+    map
+}
+
+/// How many points at least two lines cover
+pub fn overlaps(coverage: &HashMap<Point, u32>) -> usize {
+    coverage.values().filter(|&&count| count >= 2).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let lines = Day5::parse(Day5::EXAMPLE.unwrap()).unwrap();
+        assert_eq!(Day5::part1(&lines), 5);
+        assert_eq!(Day5::part2(&lines), 12);
+    }
+
+    #[test]
+    fn anywhere() {
+        let lines = Day5::parse("-5,0 -> 5,0\n0,-5 -> 0,5\n-3,-3 -> 3,3\n2000000,7 -> 2000000,7\n2000000,7 -> 1999999,8").unwrap();
+        assert_eq!(lines[2].points().collect::<Vec<_>>(), vec![(-3, -3), (-2, -2), (-1, -1), (0, 0), (1, 1), (2, 2), (3, 3)]);
+        assert_eq!(Day5::part1(&lines), 1);
+        assert_eq!(Day5::part2(&lines), 2);
+        assert_eq!(Day5::parse("0,0 -> 2,1"), Err(ParseError::Slanted { line: 1 }));
+    }
+}
@@ -1,155 +1,91 @@
-use std::fs;
+use std::{path::PathBuf, process::ExitCode};
 
-#[derive(Copy, Clone)]
-struct Line((usize, usize), (usize, usize));
+use common::{Solution, Source};
+use day_5::{render::{HeatMap, Layer}, sweep, Day5};
 
-fn main() {
+const SWEEP_USAGE: &str = "\
+Usage: day_5 --sweep [PATH | - | --example]
 
-    let input: String = fs::read_to_string("input.txt").unwrap();
-    let mut lines: Vec<Line> = Vec::new();
-    for line in input.trim().split('\n') {
-        let start_end: Vec<String> = line.split(" -> ").map(|x| x.to_string()).collect();
-        let start: Vec<String> = start_end[0].split(',').map(|x| x.to_string()).collect();
-        let end: Vec<String> = start_end[1].split(',').map(|x| x.to_string()).collect();
-        let start_x: usize = start[0].to_string().parse::<usize>().unwrap();
-        let start_y: usize = start[1].to_string().parse::<usize>().unwrap();
-        let end_x: usize = end[0].to_string().parse::<usize>().unwrap();
-        let end_y: usize = end[1].to_string().parse::<usize>().unwrap();
-        lines.push(Line((start_x, start_y), (end_x, end_y)))
-    }
-    // println!("{:?}", lines[0].1.1);
-    part_1(lines.clone());
-    part_2(lines);
-}
+Answers both parts by working out where the lines overlap rather than
+drawing every point of them, for lines too long to draw.";
 
-fn part_1(lines: Vec<Line>) {
-    let mut map: [[usize; 1000]; 1000] = [[0; 1000]; 1000];
-    for line in lines.iter() {
+const RENDER_USAGE: &str = "\
+Usage: day_5 --render LAYER [PATH | - | --example] [--out FILE.ppm | FILE.png]
 
-        if is_horizontal(&line) {
-            // println!("It's horizontal!");
-            // println!("(({},{}),({},{}))", line.0.0, line.0.1, line.1.0, line.1.1);
-            let lesser = the_lesser(line.0.0, line.1.0); // Want to make sure
-            let greater = the_greater(line.0.0, line.1.0); // the range steps up by 1
-            let range = lesser..=greater; // Need an inclusive range
-            for x in range {
-                let y: usize = line.0.1 as usize;
-                map[x][y] += 1;
-            }
-            continue; // In case any of the lines have a length of 1
-        }             // Otherwise, they would count as both horizontal and vertical
+Draws how many lines cover each point, for the LAYER of lines: `axis` (the
+ones part 1 counts), `diagonal` or `all` (part 2). Without --out the map is
+printed the way the puzzle draws it, which only suits small maps.";
+
+/// The widest (or tallest) map worth printing as text
+const MAX_ASCII_SIZE: usize = 200;
 
-        if is_vertical(&line) {
-            // println!("It's vertical!");
-            // println!("(({},{}),({},{}))", line.0.0, line.0.1, line.1.0, line.1.1);
-            let lesser = the_lesser(line.0.1, line.1.1);
-            let greater = the_greater(line.0.1, line.1.1);
-            let range = lesser..=greater;
-            for y in range {
-                let x: usize = line.0.0 as usize;
-                map[x][y] += 1;
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.split_first() {
+        Some((flag, rest)) if flag == "--sweep" => match print_swept(rest) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {e}");
+                ExitCode::FAILURE
             }
-        }
-    }
-    
-    let mut count: usize = 0;
-    for i in 1..1000 {
-        for j in 1..1000 {
-            count += (map[i][j] >= 2) as usize;
-        }
+        },
+        Some((flag, rest)) if flag == "--render" => match render(rest) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {e}");
+                ExitCode::FAILURE
+            }
+        },
+        _ => common::main::<Day5>(),
     }
-
-    // println!("{:?}", map);
-    println!("Part 1: {}", count);
 }
 
-fn part_2(lines: Vec<Line>) {
-    let mut map: [[usize; 1000]; 1000] = [[0; 1000]; 1000];
-    for line in lines.iter() {
+fn print_swept(args: &[String]) -> Result<(), String> {
+    let source = Source::from_args(&mut args.iter(), |_, _| Ok(false)).map_err(|e| format!("{e}\n\n{SWEEP_USAGE}"))?;
 
-        if is_horizontal(&line) {
-            // println!("It's horizontal!");
-            // println!("(({},{}),({},{}))", line.0.0, line.0.1, line.1.0, line.1.1);
-            let lesser = the_lesser(line.0.0, line.1.0); // Want to make sure
-            let greater = the_greater(line.0.0, line.1.0); // the range steps up by 1
-            let range = lesser..=greater; // Need an inclusive range
-            for x in range {
-                let y: usize = line.0.1 as usize;
-                map[x][y] += 1;
-            }
-            continue; // In case any of the lines have a length of 1
-        }             // Otherwise, they would count as both horizontal and vertical
+    let input = source.read(Day5::EXAMPLE)?;
+    let lines = Day5::parse(&input).map_err(|e| format!("bad input in {source}: {e}"))?;
+    println!("Part 1: {}", sweep::overlaps(lines.iter().filter(|line| line.is_axis_aligned())));
+    println!("Part 2: {}", sweep::overlaps(&lines));
+    Ok(())
+}
 
-        if is_vertical(&line) {
-            // println!("It's vertical!");
-            // println!("(({},{}),({},{}))", line.0.0, line.0.1, line.1.0, line.1.1);
-            let lesser = the_lesser(line.0.1, line.1.1);
-            let greater = the_greater(line.0.1, line.1.1);
-            let range = lesser..=greater;
-            for y in range {
-                let x: usize = line.0.0 as usize;
-                map[x][y] += 1;
-            }
-            continue;
+/// `day_5 --render` draws the map instead of solving the puzzle
+fn render(args: &[String]) -> Result<(), String> {
+    let usage = |e: String| format!("{e}\n\n{RENDER_USAGE}");
+    let (layer, args) = args.split_first().ok_or(usage("--render needs a layer".to_string()))?;
+    let layer: Layer = layer.parse().map_err(usage)?;
+    let mut out = None;
+    let source = Source::from_args(&mut args.iter(), |arg, args| {
+        if arg != "--out" {
+            return Ok(false);
         }
-
-        println!("It's diagonal!");
-        let parity1   = which_lesser(line.0.0, line.1.0);
-        let parity2   = which_lesser(line.0.1, line.1.1);
-        let lesser_x  = the_lesser(  line.0.0, line.1.0);
-        let greater_x = the_greater( line.0.0, line.1.0);
-        let lesser_y  = the_lesser(  line.0.1, line.1.1);
-        let greater_y = the_greater( line.0.1, line.1.1);
-
-        if parity1 == parity2 {
-            println!("Drawing straight!");
-            for (x,y) in (lesser_x..=greater_x).zip(lesser_y..=greater_y) {
-                map[x][y] += 1;
-            }
-        } else {
-            println!("Drawing orthogonal!");
-            for (x,y) in (lesser_x..=greater_x).zip((lesser_y..=greater_y).rev()) {
-                map[x][y] += 1;
-            }
+        let path = PathBuf::from(args.next().ok_or("--out needs a file")?);
+        let encode: fn(&HeatMap, Layer) -> Vec<u8> = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => HeatMap::to_png,
+            Some("ppm") => HeatMap::to_ppm,
+            _ => return Err(format!("expected a .png or .ppm file after --out, found `{}`", path.display())),
+        };
+        out = Some((path, encode));
+        Ok(true)
+    })
+    .map_err(usage)?;
+
+    let input = source.read(Day5::EXAMPLE)?;
+    let lines = Day5::parse(&input).map_err(|e| format!("bad input in {source}: {e}"))?;
+    let map = HeatMap::new(&lines).ok_or(format!("the lines in {source} are too far apart to draw, or there are none"))?;
+    match out {
+        Some((path, encode)) => {
+            std::fs::write(&path, encode(&map, layer)).map_err(|e| format!("couldn't write {}: {e}", path.display()))
         }
-    }
-    
-    let mut count: usize = 0;
-    for i in 1..1000 {
-        for j in 1..1000 {
-            count += (map[i][j] >= 2) as usize;
+        None if map.width() > MAX_ASCII_SIZE || map.height() > MAX_ASCII_SIZE => Err(format!(
+            "the map is {}x{} points, too big to print, so write it to an image with --out",
+            map.width(),
+            map.height()
+        )),
+        None => {
+            print!("{}", map.to_ascii(layer));
+            Ok(())
         }
     }
-
-    // println!("{:?}", map);
-    println!("Part 2: {}", count);
-}
-
-fn is_horizontal(l: &Line) -> bool {
-    l.0.1 == l.1.1
-}
-
-fn is_vertical(l: &Line) -> bool {
-    l.0.0 == l.1.0
 }
-
-fn the_greater(a: usize, b: usize) -> usize {
-    if a < b {return b;}
-    else {return a;}
-}
-
-fn the_lesser(a: usize, b: usize) -> usize {
-    if a < b {return a;}
-    else {return b;}
-}
-
-// fn which_greater(a: usize, b: usize) -> usize {
-//     if a < b {return 1;}
-//     else {return 0;}
-// }
-
-fn which_lesser(a: usize, b: usize) -> usize {
-    if a < b {return 0;}
-    else {return 1;}
-}
-
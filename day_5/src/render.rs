@@ -0,0 +1,233 @@
+//! Pictures of where the vents overlap, as ASCII like the puzzle draws it
+//! or as a PPM or PNG image, for just the axis-aligned lines part 1 looks
+//! at, just the diagonals, or all of them like part 2.
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{coverage, Line, Point};
+
+/// Which lines to draw
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Layer {
+    /// Horizontal and vertical lines, the ones part 1 counts
+    Axis,
+    Diagonal,
+    /// Everything, like part 2
+    All,
+}
+
+impl FromStr for Layer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "axis" => Ok(Layer::Axis),
+            "diagonal" => Ok(Layer::Diagonal),
+            "all" => Ok(Layer::All),
+            _ => Err(format!("expected `axis`, `diagonal` or `all`, found `{s}`")),
+        }
+    }
+}
+
+/// The most pixels an image gets, so that two far apart lines don't make
+/// for a picture nothing can open
+pub const MAX_PIXELS: u64 = 1 << 26;
+
+/// How many lines cover each point of the smallest box around them all,
+/// kept apart for axis-aligned and diagonal lines
+pub struct HeatMap {
+    /// The top left corner
+    min: Point,
+    width: usize,
+    height: usize,
+    axis: HashMap<Point, u32>,
+    diagonal: HashMap<Point, u32>,
+}
+
+impl HeatMap {
+    /// `None` if there are no lines, or they are too far apart to draw
+    pub fn new(lines: &[Line]) -> Option<Self> {
+        let points = lines.iter().flat_map(|line| [line.start, line.end]);
+        let (min_x, max_x) = (points.clone().map(|p| p.0).min()?, points.clone().map(|p| p.0).max()?);
+        let (min_y, max_y) = (points.clone().map(|p| p.1).min()?, points.map(|p| p.1).max()?);
+        let (width, height) = (min_x.abs_diff(max_x) + 1, min_y.abs_diff(max_y) + 1);
+        if width.saturating_mul(height) > MAX_PIXELS {
+            return None;
+        }
+        Some(HeatMap {
+            min: (min_x, min_y),
+            width: width as usize,
+            height: height as usize,
+            axis: coverage(lines.iter().filter(|line| line.is_axis_aligned())),
+            diagonal: coverage(lines.iter().filter(|line| !line.is_axis_aligned())),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// How many lines of the layer cover the point `col` across and `row`
+    /// down from the top left corner
+    pub fn count(&self, layer: Layer, row: usize, col: usize) -> u32 {
+        let point = (self.min.0 + col as i64, self.min.1 + row as i64);
+        let axis = || self.axis.get(&point).copied().unwrap_or(0);
+        let diagonal = || self.diagonal.get(&point).copied().unwrap_or(0);
+        match layer {
+            Layer::Axis => axis(),
+            Layer::Diagonal => diagonal(),
+            Layer::All => axis() + diagonal(),
+        }
+    }
+
+    /// The map the way the puzzle draws it: `.` where there are no lines
+    /// and otherwise how many there are, with `+` for more than 9
+    pub fn to_ascii(&self, layer: Layer) -> String {
+        let mut ascii = String::with_capacity((self.width + 1) * self.height);
+        for row in 0..self.height {
+            for col in 0..self.width {
+                ascii.push(match self.count(layer, row, col) {
+                    0 => '.',
+                    n @ 1..=9 => char::from_digit(n, 10).unwrap(),
+                    _ => '+',
+                });
+            }
+            ascii.push('\n');
+        }
+        ascii
+    }
+
+    /// Each pixel's colour, row by row
+    fn pixels(&self, layer: Layer) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.width * self.height * 3);
+        for row in 0..self.height {
+            for col in 0..self.width {
+                pixels.extend(colour(self.count(layer, row, col)));
+            }
+        }
+        pixels
+    }
+
+    /// A binary PPM image, one pixel per point
+    pub fn to_ppm(&self, layer: Layer) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.pixels(layer));
+        ppm
+    }
+
+    /// A PNG image, one pixel per point. It isn't compressed, which keeps
+    /// this short at the cost of a file about as big as the PPM.
+    pub fn to_png(&self, layer: Layer) -> Vec<u8> {
+        let pixels = self.pixels(layer);
+        // Each row starts with the filter it uses, which is none
+        let mut raw = Vec::with_capacity(pixels.len() + self.height);
+        for row in pixels.chunks(self.width * 3) {
+            raw.push(0);
+            raw.extend(row);
+        }
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits a channel, RGB, and the only compression, filter and
+        // interlacing methods there are (or none, for interlacing)
+        header.extend([8, 2, 0, 0, 0]);
+        chunk(&mut png, b"IHDR", &header);
+        chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+/// Black where there's nothing, blue for one line and hotter from there
+fn colour(count: u32) -> [u8; 3] {
+    const RAMP: [[u8; 3]; 6] =
+        [[0, 0, 0], [30, 60, 150], [220, 60, 30], [250, 150, 20], [255, 230, 60], [255, 255, 255]];
+    RAMP[(count as usize).min(RAMP.len() - 1)]
+}
+
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// `data` in a zlib stream made of stored, uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        zlib.push(blocks.peek().is_none() as u8);
+        zlib.extend((block.len() as u16).to_le_bytes());
+        zlib.extend((!(block.len() as u16)).to_le_bytes());
+        zlib.extend(block);
+    }
+    zlib.extend(adler32(data).to_be_bytes());
+    zlib
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { crc >> 1 ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    b << 16 | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day5;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let lines = Day5::parse(Day5::EXAMPLE.unwrap()).unwrap();
+        let map = HeatMap::new(&lines).unwrap();
+        assert_eq!(
+            map.to_ascii(Layer::Axis),
+            ".......1..\n..1....1..\n..1....1..\n.......1..\n.112111211\n\
+             ..........\n..........\n..........\n..........\n222111....\n"
+        );
+        assert_eq!(
+            map.to_ascii(Layer::All),
+            "1.1....11.\n.111...2..\n..2.1.111.\n...1.2.2..\n.112313211\n\
+             ...1.2....\n..1...1...\n.1.....1..\n1.......1.\n222111....\n"
+        );
+        assert!(map.to_ppm(Layer::Diagonal).starts_with(b"P6\n10 10\n255\n"));
+        assert_eq!(map.to_ppm(Layer::Diagonal).len(), 13 + 10 * 10 * 3);
+    }
+
+    #[test]
+    fn png() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        let lines = Day5::parse("-2,5 -> 1,5").unwrap();
+        let png = HeatMap::new(&lines).unwrap().to_png(Layer::All);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x04\0\0\0\x01"));
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+        assert!(HeatMap::new(&Day5::parse("0,0 -> 0,0\n100000,100000 -> 100000,100000").unwrap()).is_none());
+    }
+}
@@ -0,0 +1,284 @@
+//! Counting overlaps without drawing a single point, for lines far too long
+//! to rasterize.
+//!
+//! Every line belongs to one of four families, and along a line of one
+//! family some combination of x and y never changes: y for horizontal
+//! lines, x for vertical ones, x - y for rising diagonals and x + y for
+//! falling ones. That constant is the line's key. Lines of a family with
+//! the same key lie on top of each other, so their overlaps are where
+//! their intervals along that key overlap. Lines of different families
+//! meet in at most one point, found by sweeping over where their
+//! intervals start and end.
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::{Line, Point};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Family {
+    Horizontal,
+    Vertical,
+    /// y goes up with x
+    Rising,
+    /// y goes down as x goes up
+    Falling,
+}
+
+const FAMILIES: [Family; 4] = [Family::Horizontal, Family::Vertical, Family::Rising, Family::Falling];
+
+impl Family {
+    /// A line that is a single point counts as horizontal
+    fn of(line: &Line) -> Self {
+        match line.step() {
+            (_, 0) => Family::Horizontal,
+            (0, _) => Family::Vertical,
+            (dx, dy) if dx == dy => Family::Rising,
+            _ => Family::Falling,
+        }
+    }
+
+    /// The key of a line is `a * x + b * y` for these `(a, b)`
+    fn key_coefficients(self) -> (i64, i64) {
+        match self {
+            Family::Horizontal => (0, 1),
+            Family::Vertical => (1, 0),
+            Family::Rising => (1, -1),
+            Family::Falling => (1, 1),
+        }
+    }
+
+    fn key(self, (x, y): Point) -> i64 {
+        let (a, b) = self.key_coefficients();
+        a * x + b * y
+    }
+
+    /// Where a point is along its line: x, except on vertical lines
+    fn along_coefficients(self) -> (i64, i64) {
+        match self {
+            Family::Vertical => (0, 1),
+            _ => (1, 0),
+        }
+    }
+
+    fn along(self, (x, y): Point) -> i64 {
+        let (a, b) = self.along_coefficients();
+        a * x + b * y
+    }
+
+    /// The point `along` along the line with this `key`
+    fn point(self, key: i64, along: i64) -> Point {
+        solve(self.key_coefficients(), key, self.along_coefficients(), along).unwrap()
+    }
+}
+
+/// The point where `a.0 * x + a.1 * y == p` and `b.0 * x + b.1 * y == q`,
+/// if there is one with whole coordinates
+fn solve(a: (i64, i64), p: i64, b: (i64, i64), q: i64) -> Option<Point> {
+    let det = a.0 * b.1 - a.1 * b.0;
+    let (x, y) = (p * b.1 - a.1 * q, a.0 * q - p * b.0);
+    (det != 0 && x % det == 0 && y % det == 0).then(|| (x / det, y / det))
+}
+
+/// Where a stretch of a line starts and ends along it, both included
+type Interval = (i64, i64);
+
+/// The covered stretches of each line of a family: by key, the intervals
+/// along it, in order and not touching
+type Stretches = HashMap<i64, Vec<Interval>>;
+
+/// How many points at least two of the lines cover
+pub fn overlaps<'a>(lines: impl IntoIterator<Item = &'a Line>) -> u64 {
+    let mut intervals: HashMap<Family, HashMap<i64, Vec<Interval>>> = HashMap::new();
+    for line in lines {
+        let family = Family::of(line);
+        let (a, b) = (family.along(line.start), family.along(line.end));
+        intervals
+            .entry(family)
+            .or_default()
+            .entry(family.key(line.start))
+            .or_default()
+            .push((a.min(b), a.max(b)));
+    }
+    let mut covered: HashMap<Family, Stretches> = HashMap::new();
+    let mut doubled: HashMap<Family, Stretches> = HashMap::new();
+    for (&family, lines) in &intervals {
+        for (&key, intervals) in lines {
+            let (once, twice) = covered_stretches(intervals);
+            covered.entry(family).or_default().insert(key, once);
+            if !twice.is_empty() {
+                doubled.entry(family).or_default().insert(key, twice);
+            }
+        }
+    }
+
+    // Everything lines of the same family cover twice...
+    let mut count: u64 = doubled
+        .values()
+        .flat_map(|lines| lines.values().flatten())
+        .map(|&(lo, hi)| (hi - lo + 1) as u64)
+        .sum();
+    // ...and every crossing of different families, taking care to count
+    // each point just once however many ways it got covered twice
+    let none = Stretches::new();
+    let mut crossings = HashSet::new();
+    for (i, &f) in FAMILIES.iter().enumerate() {
+        for &g in &FAMILIES[i + 1..] {
+            let (fs, gs) = (covered.get(&f).unwrap_or(&none), covered.get(&g).unwrap_or(&none));
+            crossings.extend(crossing_points(f, fs, g, gs));
+        }
+    }
+    for point in crossings {
+        let doubled_in = FAMILIES
+            .iter()
+            .filter(|&&f| doubled.get(&f).is_some_and(|lines| contains(lines, f, point)))
+            .count() as u64;
+        match doubled_in {
+            0 => count += 1,
+            n => count -= n - 1,
+        }
+    }
+    count
+}
+
+/// The stretches `intervals` cover at least once and at least twice
+fn covered_stretches(intervals: &[Interval]) -> (Vec<Interval>, Vec<Interval>) {
+    let mut events: Vec<(i64, i32)> = intervals.iter().flat_map(|&(lo, hi)| [(lo, 1), (hi + 1, -1)]).collect();
+    events.sort_unstable();
+    let (mut once, mut twice) = (Vec::new(), Vec::new());
+    let mut depth = 0;
+    let (mut once_start, mut twice_start) = (0, 0);
+    for (at, change) in events {
+        let before = depth;
+        depth += change;
+        for (threshold, start, stretches) in [(1, &mut once_start, &mut once), (2, &mut twice_start, &mut twice)] {
+            if before < threshold && depth >= threshold {
+                *start = at;
+            } else if before >= threshold && depth < threshold && *start < at {
+                match stretches.last_mut() {
+                    // Picking up right where the last one stopped
+                    Some((_, hi)) if *hi + 1 == *start => *hi = at - 1,
+                    _ => stretches.push((*start, at - 1)),
+                }
+            }
+        }
+    }
+    (once, twice)
+}
+
+/// Whether a line of family `family` in `lines` covers `point`
+fn contains(lines: &Stretches, family: Family, point: Point) -> bool {
+    let Some(stretches) = lines.get(&family.key(point)) else { return false };
+    let along = family.along(point);
+    let i = stretches.partition_point(|&(_, hi)| hi < along);
+    stretches.get(i).is_some_and(|&(lo, _)| lo <= along)
+}
+
+/// Where the lines of family `f` meet those of family `g`. Seen with f's
+/// key going up the page and g's across it, f's lines are horizontal and
+/// g's vertical, so it's a sweep from left to right: an f stretch is there
+/// from where it starts to where it ends, and each g stretch meets the ones
+/// there as it goes by whose keys it spans.
+fn crossing_points(f: Family, fs: &Stretches, g: Family, gs: &Stretches) -> Vec<Point> {
+    // What a stretch of a line of family `h` spans of the other family's key
+    let span = |h: Family, other: Family, key: i64, (lo, hi): Interval| {
+        let (a, b) = (other.key(h.point(key, lo)), other.key(h.point(key, hi)));
+        (a.min(b), a.max(b))
+    };
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    enum Event {
+        Start(i64),
+        Cross { key: i64, from: i64, to: i64 },
+        End(i64),
+    }
+    let mut events = Vec::new();
+    for (&key, stretches) in fs {
+        for &stretch in stretches {
+            let (from, to) = span(f, g, key, stretch);
+            events.push((from, Event::Start(key)));
+            events.push((to, Event::End(key)));
+        }
+    }
+    for (&key, stretches) in gs {
+        for &stretch in stretches {
+            let (from, to) = span(g, f, key, stretch);
+            events.push((key, Event::Cross { key, from, to }));
+        }
+    }
+    events.sort_unstable();
+
+    let mut active: BTreeMap<i64, usize> = BTreeMap::new();
+    let mut points = Vec::new();
+    for (_, event) in events {
+        match event {
+            Event::Start(key) => *active.entry(key).or_insert(0) += 1,
+            Event::End(key) => match active.get_mut(&key) {
+                Some(1) => {
+                    active.remove(&key);
+                }
+                Some(n) => *n -= 1,
+                None => unreachable!("every stretch ends after it starts"),
+            },
+            Event::Cross { key, from, to } => {
+                // Diagonals of opposite directions can pass between each
+                // other without sharing a point, which `solve` sorts out
+                let (fc, gc) = (f.key_coefficients(), g.key_coefficients());
+                points.extend(active.range(from..=to).filter_map(|(&fk, _)| solve(fc, fk, gc, key)));
+            }
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coverage, Day5};
+    use common::Solution;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn rasterized<'a>(lines: impl IntoIterator<Item = &'a Line>) -> u64 {
+        crate::overlaps(&coverage(lines)) as u64
+    }
+
+    #[test]
+    fn example() {
+        let lines = Day5::parse(Day5::EXAMPLE.unwrap()).unwrap();
+        assert_eq!(overlaps(lines.iter().filter(|l| l.is_axis_aligned())), 5);
+        assert_eq!(overlaps(&lines), 12);
+    }
+
+    #[test]
+    fn long_lines() {
+        let lines = Day5::parse(
+            "0,0 -> 10000000,0\n5000000,0 -> 20000000,0\n-3,-3 -> 10000000,10000000\n\
+             0,10000000 -> 10000000,0\n7,-100 -> 7,100",
+        )
+        .unwrap();
+        // The horizontal lines share 5000001 points. Then there are 0,0 and
+        // 7,7 on the rising diagonal, 5000000,5000000 where the diagonals
+        // cross and 7,0, but not 10000000,0, which is already counted.
+        assert_eq!(overlaps(&lines), 5000001 + 4);
+    }
+
+    #[test]
+    fn matches_rasterizing() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for round in 0..300 {
+            // Small maps, so that lines pile up on each other a lot
+            let size = 4 + round % 20;
+            let lines: Vec<Line> = (0..rng.gen_range(1..30))
+                .map(|_| {
+                    let start = (rng.gen_range(-size..size), rng.gen_range(-size..size));
+                    let length = rng.gen_range(0..size);
+                    let (dx, dy) = [(1, 0), (0, 1), (1, 1), (1, -1), (-1, 0), (0, -1), (-1, -1), (-1, 1)][rng.gen_range(0..8)];
+                    Line { start, end: (start.0 + dx * length, start.1 + dy * length) }
+                })
+                .collect();
+            assert_eq!(
+                overlaps(lines.iter().filter(|l| l.is_axis_aligned())),
+                rasterized(lines.iter().filter(|l| l.is_axis_aligned())),
+                "{lines:?}"
+            );
+            assert_eq!(overlaps(&lines), rasterized(&lines), "{lines:?}");
+        }
+    }
+}
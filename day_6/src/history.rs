@@ -0,0 +1,145 @@
+//! The population day by day rather than just at the end, for seeing how
+//! fast it grows and which timers the fish are on along the way.
+use std::fmt::{Display, Formatter};
+
+use crate::model::Model;
+
+/// How many fish there are on `day`, by timer and altogether
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Snapshot {
+    pub day: usize,
+    pub total: u128,
+    /// How many fish have each timer, from 0 up
+    pub timers: Vec<u128>,
+}
+
+impl Snapshot {
+    fn of(day: usize, timers: Vec<u128>) -> Option<Self> {
+        let total = timers.iter().try_fold(0u128, |total, &n| total.checked_add(n))?;
+        Some(Snapshot { day, total, timers })
+    }
+}
+
+/// The population has grown too big to count in a `u128`, which with the
+/// puzzle's fish takes about 1000 days
+#[derive(Debug, Eq, PartialEq)]
+pub struct Overflow {
+    pub day: usize,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "too many fish to count on day {}", self.day)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// The population on every day from 0, the day of the census, to `days`
+pub fn history(model: Model, census: &[u64], days: usize) -> Result<Vec<Snapshot>, Overflow> {
+    let mut timers: Vec<u128> = census.iter().map(|&n| n as u128).collect();
+    // Grown as it goes rather than sized for `days` up front, since the
+    // fish usually overflow long before a huge number of days is up
+    let mut snapshots = Vec::new();
+    for day in 0..=days {
+        if day > 0 {
+            timers = model.checked_step(&timers).ok_or(Overflow { day })?;
+        }
+        snapshots.push(Snapshot::of(day, timers.clone()).ok_or(Overflow { day })?);
+    }
+    Ok(snapshots)
+}
+
+/// The first day there are more than `threshold` fish, or `None` if there
+/// are no fish to begin with
+pub fn first_exceeding(model: Model, census: &[u64], threshold: u128) -> Option<usize> {
+    if census.iter().all(|&n| n == 0) {
+        return None;
+    }
+    // Every fish spawns before long, so this gets there sooner or later,
+    // and a count too big for a u128 is certainly past the threshold
+    let mut timers: Vec<u128> = census.iter().map(|&n| n as u128).collect();
+    for day in 0.. {
+        match Snapshot::of(day, timers.clone()) {
+            Some(snapshot) if snapshot.total <= threshold => {}
+            _ => return Some(day),
+        }
+        match model.checked_step(&timers) {
+            Some(next) => timers = next,
+            None => return Some(day + 1),
+        }
+    }
+    unreachable!("the population grows past any u128 eventually")
+}
+
+/// The history as CSV, a column for each timer after the total
+pub fn to_csv(snapshots: &[Snapshot]) -> String {
+    let timers = snapshots.first().map_or(0, |s| s.timers.len());
+    let mut csv = String::from("day,total");
+    for timer in 0..timers {
+        csv.push_str(&format!(",timer_{timer}"));
+    }
+    csv.push('\n');
+    for s in snapshots {
+        csv.push_str(&format!("{},{}", s.day, s.total));
+        for n in &s.timers {
+            csv.push_str(&format!(",{n}"));
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// The history as a JSON array with an object for each day
+pub fn to_json(snapshots: &[Snapshot]) -> String {
+    let days: Vec<String> = snapshots
+        .iter()
+        .map(|s| {
+            let timers: Vec<String> = s.timers.iter().map(|n| n.to_string()).collect();
+            format!(r#"{{"day":{},"total":{},"timers":[{}]}}"#, s.day, s.total, timers.join(","))
+        })
+        .collect();
+    format!("[{}]", days.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day6;
+    use common::Solution;
+
+    #[test]
+    fn example() {
+        let fishes = Day6::parse(Day6::EXAMPLE.unwrap()).unwrap();
+        let model = Model::default();
+        let census = model.census(&fishes);
+        let snapshots = history(model, &census, 80).unwrap();
+        assert_eq!(snapshots.len(), 81);
+        assert_eq!(snapshots[18], Snapshot { day: 18, total: 26, timers: vec![3, 5, 3, 2, 2, 1, 5, 1, 4] });
+        assert_eq!(snapshots[80].total, 5934);
+        let csv = to_csv(&snapshots[..2]);
+        assert_eq!(
+            csv,
+            "day,total,timer_0,timer_1,timer_2,timer_3,timer_4,timer_5,timer_6,timer_7,timer_8\n\
+             0,5,0,1,1,2,1,0,0,0,0\n1,5,1,1,2,1,0,0,0,0,0\n"
+        );
+        assert_eq!(
+            to_json(&snapshots[..1]),
+            r#"[{"day":0,"total":5,"timers":[0,1,1,2,1,0,0,0,0]}]"#
+        );
+        assert!(history(model, &census, 2000).is_err());
+        assert!(history(model, &census, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn thresholds() {
+        let model = Model::default();
+        let census = model.census(&Day6::parse(Day6::EXAMPLE.unwrap()).unwrap());
+        assert_eq!(first_exceeding(model, &census, 4), Some(0));
+        assert_eq!(first_exceeding(model, &census, 5), Some(2));
+        assert_eq!(first_exceeding(model, &census, 26), Some(19));
+        assert_eq!(first_exceeding(model, &census, 5934), Some(81));
+        assert!(first_exceeding(model, &census, u128::MAX).is_some());
+        assert_eq!(first_exceeding(model, &model.census(&[]), 0), None);
+    }
+}
@@ -0,0 +1,71 @@
+pub mod history;
+pub mod model;
+
+use std::fmt::{Display, Formatter};
+use common::{Position, Solution};
+use crate::model::Model;
+
+/// What can go wrong reading the list of lanternfish
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Each fish is a comma separated timer from 0 up to the highest the
+    /// model's fish can have, 8 for the puzzle's
+    NotATimer { at: Position, found: String, highest: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotATimer { at, found, highest } => {
+                write!(f, "{at}: expected a timer from 0 to {highest}, found `{found}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The timer of every fish, none of them higher than a fish of `model`
+/// could ever have
+pub fn parse_fishes(input: &str, model: &Model) -> Result<Vec<usize>, ParseError> {
+    let highest = model.reset.max(model.newborn);
+    let mut fishes = Vec::new();
+    for (i, line) in input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        for x in line.split(',').map(str::trim) {
+            match x.parse::<usize>() {
+                Ok(timer) if timer <= highest => fishes.push(timer),
+                _ => return Err(ParseError::NotATimer {
+                    at: Position::of(i, line, x),
+                    found: x.to_string(),
+                    highest,
+                }),
+            }
+        }
+    }
+    Ok(fishes)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<usize>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = u128;
+    const DAY: u8 = 6;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_fishes(input, &Model::default())
+    }
+
+    fn part1(fishes: &Vec<usize>) -> usize {
+        let model = Model::default();
+        model.simulate(&model.census(fishes), 80) as usize
+    }
+
+    fn part2(fishes: &Vec<usize>) -> u128 {
+        let model = Model::default();
+        model.simulate(&model.census(fishes), 256)
+    }
+}
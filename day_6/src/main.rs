@@ -1,58 +1,132 @@
-use std::fs;
+use std::process::ExitCode;
 
-fn main() {
-    let mut fishes: Vec<usize> = fs::read_to_string("input.txt")
-        .unwrap()
-        .trim()
-        .split(',')
-        .map(|x| x.to_string().parse::<usize>().unwrap())
-        .collect();
+use common::{Solution, Source};
+use day_6::{history, model::{Exact, Model, Modulo, MAX_EXACT_DAYS}, parse_fishes, Day6};
 
-    for _ in 0..80 {
-        fishes = step(fishes);
+const USAGE: &str = "\
+Usage: day_6 --days N [PATH | - | --example] [--model RESET,NEWBORN] [--modulo M]
+       day_6 --history N [PATH | - | --example] [--model RESET,NEWBORN] [--json]
+       day_6 --threshold N [PATH | - | --example] [--model RESET,NEWBORN]
+
+--days counts the fish after N days. Every digit gets printed for up to
+1000000 days; past that, or with --modulo, just the count modulo M.
+--history prints the count of every timer on every day up to N as CSV, or
+JSON with --json. --threshold finds the first day there are more than N
+fish.
+
+The fish reset to RESET when they spawn and start out at NEWBORN, 6,8
+unless told otherwise.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.split_first() {
+        Some((flag, rest)) if flag == "--days" => print_population(rest),
+        Some((flag, rest)) if flag == "--history" => print_history(rest),
+        Some((flag, rest)) if flag == "--threshold" => print_threshold(rest),
+        _ => return common::main::<Day6>(),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
     }
-    println!("Part 1: {}", fishes.len());
+}
+
+fn usage(e: String) -> String {
+    format!("{e}\n\n{USAGE}")
+}
+
+/// What comes after the mode and its number
+struct Options {
+    source: Source,
+    model: Model,
+    modulus: Option<u64>,
+    json: bool,
+}
 
-///////////////////////////////////////////////
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let (mut model, mut modulus, mut json) = (Model::default(), None, false);
+        let source = Source::from_args(&mut args.iter(), |arg, args| {
+            match arg {
+                "--model" => {
+                    let value = args.next().ok_or("--model needs the reset and newborn timers")?;
+                    model = value.parse()?;
+                }
+                "--modulo" => {
+                    let value = args.next().ok_or("--modulo needs a number")?;
+                    modulus = Some(
+                        value.parse::<u64>().ok().filter(|&m| m > 0)
+                            .ok_or(format!("expected a modulus of at least 1, found `{value}`"))?,
+                    );
+                }
+                "--json" => json = true,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })
+        .map_err(usage)?;
+        Ok(Options { source, model, modulus, json })
+    }
 
-    fishes = fs::read_to_string("input.txt")
-        .unwrap()
-        .trim()
-        .split(',')
-        .map(|x| x.to_string().parse::<usize>().unwrap())
-        .collect();
+    /// How many fish the input has of each timer
+    fn census(&self) -> Result<Vec<u64>, String> {
+        let input = self.source.read(Day6::EXAMPLE)?;
+        let fishes = parse_fishes(&input, &self.model).map_err(|e| format!("bad input in {}: {e}", self.source))?;
+        Ok(self.model.census(&fishes))
+    }
+}
 
-    let mut count: [u128; 9] = [0; 9];
-    for f in fishes {
-        count[f] += 1;
+/// The mode's number and the options after it, turning away the options
+/// the mode has no use for
+fn mode_args<N: std::str::FromStr>(mode: &str, args: &[String], modulo: bool, json: bool) -> Result<(N, Options), String> {
+    let (n, args) = args.split_first().ok_or(usage(format!("{mode} needs a number")))?;
+    let n = n.parse().map_err(|_| usage(format!("expected a number after {mode}, found `{n}`")))?;
+    let options = Options::parse(args)?;
+    if options.modulus.is_some() && !modulo {
+        return Err(usage(format!("{mode} doesn't take --modulo")));
     }
+    if options.json && !json {
+        return Err(usage(format!("{mode} doesn't take --json")));
+    }
+    Ok((n, options))
+}
 
-    for _ in 0..256 {
-        count = step2(count);
+/// `day_6 --days` counts the fish for any number of days
+fn print_population(args: &[String]) -> Result<(), String> {
+    let (days, options): (u64, _) = mode_args("--days", args, true, false)?;
+    let census = options.census()?;
+    let model = options.model;
+    match options.modulus {
+        Some(m) => println!("{} (mod {m})", model.population(&census, days, &Modulo(m))),
+        None if days > MAX_EXACT_DAYS => {
+            return Err(format!("{days} days is too many to print every digit, so pick a modulus with --modulo"));
+        }
+        None => println!("{}", model.population(&census, days, &Exact)),
     }
-    println!("Part 2: {}", count.iter().sum::<u128>());
+    Ok(())
 }
 
-fn step(v: Vec<usize>) -> Vec<usize> {
-    let mut n: usize = 0;
-    let mut new: Vec<usize> = v
-        .iter()
-        .map(|x| if *x == 0 {
-            n += 1;
-            return 6;
-        } else {
-            return x - 1;
-        }).collect();
-    for _ in 0..n {new.push(8);}
-    new
+/// `day_6 --history` prints the population on every day
+fn print_history(args: &[String]) -> Result<(), String> {
+    let (days, options): (usize, _) = mode_args("--history", args, false, true)?;
+    let snapshots = history::history(options.model, &options.census()?, days).map_err(|e| e.to_string())?;
+    if options.json {
+        println!("{}", history::to_json(&snapshots));
+    } else {
+        print!("{}", history::to_csv(&snapshots));
+    }
+    Ok(())
 }
 
-fn step2(v: [u128; 9]) -> [u128; 9] {
-    let mut new: [u128; 9] = [0; 9];
-    new[8] = v[0];
-    new[6] = v[0];
-    for i in 0..8 {
-        new[i] += v[i + 1];
+/// `day_6 --threshold` finds when the population gets past some size
+fn print_threshold(args: &[String]) -> Result<(), String> {
+    let (threshold, options): (u128, _) = mode_args("--threshold", args, false, false)?;
+    match history::first_exceeding(options.model, &options.census()?, threshold) {
+        Some(day) => println!("day {day}"),
+        None => return Err(format!("there are no fish in {}, so never", options.source)),
     }
-    new
+    Ok(())
 }
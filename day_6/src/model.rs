@@ -0,0 +1,260 @@
+//! Lanternfish with any life cycle, counted for any number of days.
+//!
+//! Fish with the same timer all do the same thing, so all that matters is
+//! how many there are of each timer. A day takes those counts to the next
+//! day's by a fixed linear map, the same matrix every day, and so `days`
+//! days later they are that matrix to the power of `days` times today's.
+//! Squaring the matrix over and over gets there in about log2(days) steps.
+use std::str::FromStr;
+
+use common::big::BigUint;
+
+/// When fish spawn and how long the new ones take to grow up
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Model {
+    /// The timer of a fish that has just spawned
+    pub reset: usize,
+    /// The timer of a newborn fish
+    pub newborn: usize,
+}
+
+impl Default for Model {
+    /// The puzzle's fish: every 7 days, with 2 more for a newborn
+    fn default() -> Self {
+        Model { reset: 6, newborn: 8 }
+    }
+}
+
+impl FromStr for Model {
+    type Err = String;
+
+    /// The reset and newborn timers, like `6,8`
+    fn from_str(s: &str) -> Result<Self, String> {
+        s.split_once(',')
+            .and_then(|(reset, newborn)| Some(Model { reset: reset.trim().parse().ok()?, newborn: newborn.trim().parse().ok()? }))
+            .ok_or(format!("expected the reset and newborn timers like `6,8`, found `{s}`"))
+    }
+}
+
+impl Model {
+    /// How many fish there are of each timer, from 0 up to whatever the
+    /// oldest fish, the reset or the newborn timer needs
+    pub fn census(&self, fishes: &[usize]) -> Vec<u64> {
+        let highest = fishes.iter().copied().chain([self.reset, self.newborn]).max().unwrap();
+        let mut count = vec![0; highest + 1];
+        for &fish in fishes {
+            count[fish] += 1;
+        }
+        count
+    }
+
+    /// The counts of each timer a day after `count`
+    pub fn step(&self, count: &[u128]) -> Vec<u128> {
+        self.checked_step(count).expect("too many fish to count")
+    }
+
+    /// [`Model::step`], or `None` if some count gets too big for a `u128`
+    pub fn checked_step(&self, count: &[u128]) -> Option<Vec<u128>> {
+        let mut next = count[1..].to_vec();
+        next.push(0);
+        next[self.reset] = next[self.reset].checked_add(count[0])?;
+        next[self.newborn] = next[self.newborn].checked_add(count[0])?;
+        Some(next)
+    }
+
+    /// How many fish there are `days` after `census`, a day at a time
+    pub fn simulate(&self, census: &[u64], days: usize) -> u128 {
+        let mut count: Vec<u128> = census.iter().map(|&n| n as u128).collect();
+        for _ in 0..days {
+            count = self.step(&count);
+        }
+        count.iter().sum()
+    }
+
+    /// Which timer each fish ends up with after a day, as a matrix taking
+    /// the counts of one day to the next: row `i` says where the fish with
+    /// timer `i` tomorrow come from today
+    fn transition(&self, timers: usize) -> Vec<Vec<u64>> {
+        let mut matrix = vec![vec![0; timers]; timers];
+        for (i, row) in matrix.iter_mut().enumerate().take(timers - 1) {
+            row[i + 1] = 1;
+        }
+        matrix[self.reset][0] += 1;
+        matrix[self.newborn][0] += 1;
+        matrix
+    }
+
+    /// How many fish there are `days` after `census`, however many days
+    /// that is, in whatever `arithmetic` counts with
+    pub fn population<A: Arithmetic>(&self, census: &[u64], days: u64, arithmetic: &A) -> A::Number {
+        let lift = |rows: &[Vec<u64>]| -> Matrix<A::Number> {
+            rows.iter().map(|row| row.iter().map(|&n| arithmetic.number(n)).collect()).collect()
+        };
+        let mut power = lift(&self.transition(census.len()));
+        let mut count: Vec<A::Number> = census.iter().map(|&n| arithmetic.number(n)).collect();
+        let mut days = days;
+        while days > 0 {
+            if days & 1 == 1 {
+                count = power.iter().map(|row| dot(arithmetic, row, &count)).collect();
+            }
+            days >>= 1;
+            if days > 0 {
+                power = square(arithmetic, &power);
+            }
+        }
+        count.iter().fold(arithmetic.number(0), |total, n| arithmetic.add(&total, n))
+    }
+}
+
+/// How to add and multiply the counts of fish
+pub trait Arithmetic {
+    type Number: Clone;
+
+    fn number(&self, n: u64) -> Self::Number;
+    fn add(&self, a: &Self::Number, b: &Self::Number) -> Self::Number;
+    fn mul(&self, a: &Self::Number, b: &Self::Number) -> Self::Number;
+}
+
+/// Every digit of the answer, which gets about one digit longer every 27
+/// days with the puzzle's fish
+pub struct Exact;
+
+/// The most days [`Exact`] is worth counting for. The answer is nearly
+/// 38000 digits long by then, which takes a couple of seconds.
+pub const MAX_EXACT_DAYS: u64 = 1_000_000;
+
+impl Arithmetic for Exact {
+    type Number = BigUint;
+
+    fn number(&self, n: u64) -> BigUint {
+        BigUint::from(n)
+    }
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a + b
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a * b
+    }
+}
+
+/// The answer modulo some number, for when there are too many days for
+/// every digit
+pub struct Modulo(pub u64);
+
+impl Arithmetic for Modulo {
+    type Number = u64;
+
+    fn number(&self, n: u64) -> u64 {
+        n % self.0
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> u64 {
+        ((*a as u128 + *b as u128) % self.0 as u128) as u64
+    }
+
+    fn mul(&self, a: &u64, b: &u64) -> u64 {
+        (*a as u128 * *b as u128 % self.0 as u128) as u64
+    }
+}
+
+type Matrix<N> = Vec<Vec<N>>;
+
+fn dot<A: Arithmetic>(arithmetic: &A, a: &[A::Number], b: &[A::Number]) -> A::Number {
+    a.iter().zip(b).fold(arithmetic.number(0), |total, (x, y)| arithmetic.add(&total, &arithmetic.mul(x, y)))
+}
+
+fn square<A: Arithmetic>(arithmetic: &A, matrix: &Matrix<A::Number>) -> Matrix<A::Number> {
+    let n = matrix.len();
+    let columns: Matrix<A::Number> = (0..n).map(|j| matrix.iter().map(|row| row[j].clone()).collect()).collect();
+    matrix.iter().map(|row| columns.iter().map(|column| dot(arithmetic, row, column)).collect()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day6;
+    use common::Solution;
+
+    /// The puzzle's fish, exactly as part 2 used to count them
+    fn step2(v: [u128; 9]) -> [u128; 9] {
+        let mut new: [u128; 9] = [0; 9];
+        new[8] = v[0];
+        new[6] = v[0];
+        for i in 0..8 {
+            new[i] += v[i + 1];
+        }
+        new
+    }
+
+    /// One number per fish, spawning new ones at the end
+    fn slow_step(model: Model, fishes: Vec<usize>) -> Vec<usize> {
+        let mut newborns = 0;
+        let mut fishes: Vec<usize> = fishes
+            .into_iter()
+            .map(|fish| if fish == 0 { newborns += 1; model.reset } else { fish - 1 })
+            .collect();
+        fishes.extend(std::iter::repeat_n(model.newborn, newborns));
+        fishes
+    }
+
+    #[test]
+    fn matches_step2() {
+        let fishes = Day6::parse(Day6::EXAMPLE.unwrap()).unwrap();
+        let model = Model::default();
+        let census = model.census(&fishes);
+        let mut count = [0; 9];
+        for &fish in &fishes {
+            count[fish] += 1;
+        }
+        for days in 0..=300 {
+            let total: u128 = count.iter().sum();
+            assert_eq!(model.simulate(&census, days), total);
+            assert_eq!(model.population(&census, days as u64, &Exact).to_string(), total.to_string());
+            assert_eq!(model.population(&census, days as u64, &Modulo(1_000_000_007)), (total % 1_000_000_007) as u64);
+            count = step2(count);
+        }
+    }
+
+    #[test]
+    fn other_life_cycles() {
+        let fishes = vec![0, 3, 3, 5, 9];
+        for model in [Model { reset: 2, newborn: 4 }, Model { reset: 4, newborn: 1 }, Model { reset: 0, newborn: 0 }] {
+            let census = model.census(&fishes);
+            let mut slow = fishes.clone();
+            for days in 0..18 {
+                assert_eq!(model.population(&census, days, &Exact).to_string(), slow.len().to_string(), "{model:?}");
+                slow = slow_step(model, slow);
+            }
+        }
+        // Fish as old as the model's newborns, and no older
+        let long_lived = Model { reset: 10, newborn: 12 };
+        assert_eq!(crate::parse_fishes("12,0", &long_lived), Ok(vec![12, 0]));
+        assert_eq!(
+            crate::parse_fishes("12,0", &Model::default()).err().map(|e| e.to_string()),
+            Some("line 1, column 1: expected a timer from 0 to 8, found `12`".to_string())
+        );
+        assert!(crate::parse_fishes("3,4", &Model { reset: 2, newborn: 3 }).is_err());
+        assert_eq!("3, 5".parse(), Ok(Model { reset: 3, newborn: 5 }));
+        assert!("3".parse::<Model>().is_err());
+    }
+
+    #[test]
+    fn huge_horizons() {
+        let fishes = Day6::parse(Day6::EXAMPLE.unwrap()).unwrap();
+        let model = Model::default();
+        let census = model.census(&fishes);
+        // Every digit for a few thousand days, checked against the
+        // remainder worked out on its own
+        let modulus = 998_244_353;
+        let exact = model.population(&census, 3000, &Exact).to_string();
+        let remainder = exact.bytes().fold(0, |r, digit| (r * 10 + (digit - b'0') as u64) % modulus);
+        assert_eq!(remainder, model.population(&census, 3000, &Modulo(modulus)));
+        assert_eq!(exact.len(), 115);
+        // And a quadrillion days takes no time at all
+        let far = model.population(&census, 1_000_000_000_000_000, &Modulo(modulus));
+        assert!(far < modulus);
+        assert_eq!(model.population(&census, 1_000_000_000_000_000, &Modulo(1)), 0);
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt::{Display, Formatter};
+use common::{Position, Solution};
+
+/// What can go wrong reading the crabs' positions
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The positions are comma separated whole numbers, which can be negative
+    NotAPosition { at: Position, found: String },
+    /// There has to be at least one crab to line up
+    NoCrabs,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotAPosition { at, found } => {
+                write!(f, "{at}: expected a horizontal position, found `{found}`")
+            }
+            ParseError::NoCrabs => write!(f, "expected at least one crab, found none"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// What it costs a crab to move some distance. Any cost that never goes
+/// down as the distance goes up, and goes up at least as fast the further
+/// it is, makes the total fuel convex in the position, which is all
+/// [`align`] needs to search for the cheapest one.
+pub trait FuelCost {
+    /// The fuel a crab burns moving `distance` steps
+    fn cost(&self, distance: u64) -> u128;
+
+    /// Positions one of which is the cheapest, for a cost that knows where
+    /// to look, or `None` to search for it
+    fn candidates(&self, _crabs: &[i64]) -> Option<Vec<i64>> {
+        None
+    }
+}
+
+/// A unit of fuel a step, as the puzzle first thinks. The median crab is
+/// as good a place as any to line up.
+pub struct Linear;
+
+impl FuelCost for Linear {
+    fn cost(&self, distance: u64) -> u128 {
+        distance as u128
+    }
+
+    fn candidates(&self, crabs: &[i64]) -> Option<Vec<i64>> {
+        let mut crabs = crabs.to_vec();
+        let middle = crabs.len() / 2;
+        Some(vec![*crabs.select_nth_unstable(middle).1])
+    }
+}
+
+/// One more unit of fuel each step than the last, so `d * (d + 1) / 2` for
+/// `d` steps. Lining up at the mean would be best if crabs could stop
+/// between positions, and that point is within half a step of it.
+pub struct Triangular;
+
+impl FuelCost for Triangular {
+    fn cost(&self, distance: u64) -> u128 {
+        distance as u128 * (distance as u128 + 1) / 2
+    }
+
+    fn candidates(&self, crabs: &[i64]) -> Option<Vec<i64>> {
+        // The mean rounded to the nearest position, so one step either side
+        // of it covers both positions around the best point in between
+        let (sum, n) = (crabs.iter().map(|&crab| crab as i128).sum::<i128>(), crabs.len() as i128);
+        let mean = (2 * sum + n).div_euclid(2 * n) as i64;
+        Some(vec![mean.saturating_sub(1), mean, mean.saturating_add(1)])
+    }
+}
+
+/// Any function of the distance will do, so long as it's convex
+impl<F: Fn(u64) -> u128> FuelCost for F {
+    fn cost(&self, distance: u64) -> u128 {
+        self(distance)
+    }
+}
+
+/// Where the crabs line up and how much fuel that takes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Alignment {
+    pub position: i64,
+    pub fuel: u128,
+}
+
+/// All the fuel it takes for the crabs to get to `position`, or `u128::MAX`
+/// if that's more than a `u128` holds
+pub fn total_fuel(crabs: &[i64], cost: &impl FuelCost, position: i64) -> u128 {
+    crabs.iter().fold(0u128, |total, &crab| total.saturating_add(cost.cost(crab.abs_diff(position))))
+}
+
+/// The cheapest position to line the crabs up at. Where several positions
+/// cost the same, this is the leftmost of the ones it looked at.
+pub fn align(crabs: &[i64], cost: &impl FuelCost) -> Alignment {
+    assert!(!crabs.is_empty(), "there are no crabs to line up");
+    let at = |position| Alignment { position, fuel: total_fuel(crabs, cost, position) };
+    let cheapest = |positions: &mut dyn Iterator<Item = i64>| {
+        positions.map(at).min_by_key(|a| (a.fuel, a.position)).unwrap()
+    };
+    if let Some(candidates) = cost.candidates(crabs) {
+        return cheapest(&mut candidates.into_iter());
+    }
+
+    // Ternary search, since going past the best position only ever costs
+    // more. If two positions cost the same, the best is somewhere between.
+    let (mut lo, mut hi) = (*crabs.iter().min().unwrap(), *crabs.iter().max().unwrap());
+    while hi.abs_diff(lo) > 2 {
+        let third = (hi.abs_diff(lo) / 3) as i64;
+        let (m1, m2) = (lo + third, hi - third);
+        let (f1, f2) = (total_fuel(crabs, cost, m1), total_fuel(crabs, cost, m2));
+        if f1 < f2 {
+            hi = m2 - 1;
+        } else if f1 > f2 {
+            lo = m1 + 1;
+        } else {
+            (lo, hi) = (m1, m2);
+        }
+    }
+    cheapest(&mut (lo..=hi))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i64>;
+    type Error = ParseError;
+    type Answer1 = u128;
+    type Answer2 = u128;
+    const DAY: u8 = 7;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        let mut crabs = Vec::new();
+        for (i, line) in input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            for x in line.split(',').map(str::trim) {
+                crabs.push(x.parse::<i64>().map_err(|_| ParseError::NotAPosition {
+                    at: Position::of(i, line, x),
+                    found: x.to_string(),
+                })?);
+            }
+        }
+        if crabs.is_empty() {
+            return Err(ParseError::NoCrabs);
+        }
+        common::debug!(1, "{} crabs", crabs.len());
+        Ok(crabs)
+    }
+
+    fn part1(crabs: &Vec<i64>) -> u128 {
+        let alignment = align(crabs, &Linear);
+        common::debug!(1, "lining up at {}", alignment.position);
+        alignment.fuel
+    }
+
+    fn part2(crabs: &Vec<i64>) -> u128 {
+        let alignment = align(crabs, &Triangular);
+        common::debug!(1, "lining up at {}", alignment.position);
+        alignment.fuel
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Trying every position from the leftmost crab to the rightmost
+    fn slow_align(crabs: &[i64], cost: &impl FuelCost) -> u128 {
+        let (min, max) = (*crabs.iter().min().unwrap(), *crabs.iter().max().unwrap());
+        (min..=max).map(|position| total_fuel(crabs, cost, position)).min().unwrap()
+    }
+
+    #[test]
+    fn example() {
+        let crabs = Day7::parse(Day7::EXAMPLE.unwrap()).unwrap();
+        assert_eq!(align(&crabs, &Linear), Alignment { position: 2, fuel: 37 });
+        assert_eq!(align(&crabs, &Triangular), Alignment { position: 5, fuel: 168 });
+        // Searching gets there too
+        assert_eq!(align(&crabs, &|d: u64| d as u128).fuel, 37);
+        assert_eq!(align(&crabs, &|d: u64| Triangular.cost(d)), Alignment { position: 5, fuel: 168 });
+    }
+
+    #[test]
+    fn anywhere() {
+        let crabs = Day7::parse("-5,-3,-3,10").unwrap();
+        assert_eq!(align(&crabs, &Linear).fuel, 15);
+        assert_eq!(align(&crabs, &Triangular).fuel, slow_align(&crabs, &Triangular));
+        let far = [i64::MIN, i64::MAX, 0];
+        assert_eq!(align(&far, &Linear), Alignment { position: 0, fuel: u64::MAX as u128 });
+        assert_eq!(align(&far, &|d: u64| d as u128), Alignment { position: 0, fuel: u64::MAX as u128 });
+        assert_eq!(align(&[i64::MIN; 3], &Triangular), Alignment { position: i64::MIN, fuel: 0 });
+    }
+
+    #[test]
+    fn matches_every_position() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let cubed = |d: u64| (d as u128).pow(3);
+        let stepped = |d: u64| d.saturating_sub(3) as u128;
+        for round in 0..500 {
+            let spread = 2 + round % 100;
+            let crabs: Vec<i64> = (0..rng.gen_range(1..=12)).map(|_| rng.gen_range(-spread..spread)).collect();
+            assert_eq!(align(&crabs, &Linear).fuel, slow_align(&crabs, &Linear), "{crabs:?}");
+            assert_eq!(align(&crabs, &Triangular).fuel, slow_align(&crabs, &Triangular), "{crabs:?}");
+            assert_eq!(align(&crabs, &cubed).fuel, slow_align(&crabs, &cubed), "{crabs:?}");
+            assert_eq!(align(&crabs, &stepped).fuel, slow_align(&crabs, &stepped), "{crabs:?}");
+        }
+    }
+}
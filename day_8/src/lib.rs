@@ -0,0 +1,274 @@
+use std::{collections::{HashMap, HashSet}, fmt::{Display, Formatter}};
+use common::{Position, Solution};
+
+#[macro_use]
+extern crate lazy_static;
+
+lazy_static! {
+    static ref DIGITS: HashMap<usize, HashSet<char>> = HashMap::from([
+        (0, HashSet::from(['a','b','c','e','f','g'])),
+        (1, HashSet::from(['c','f'])),
+        (2, HashSet::from(['a','c','d','e','g'])),
+        (3, HashSet::from(['a','c','d','f','g'])),
+        (4, HashSet::from(['b','c','d','f'])),
+        (5, HashSet::from(['a','b','d','f','g'])),
+        (6, HashSet::from(['a','b','d','e','f','g'])),
+        (7, HashSet::from(['a','c','f'])),
+        (8, HashSet::from(['a','b','c','d','e','f','g'])),
+        (9, HashSet::from(['a','b','c','d','f','g'])),
+    ]);
+}
+
+/// What can go wrong reading the notes on the displays
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Every line is the ten patterns, ` | `, then the four output digits
+    NoDivider { line: usize },
+    /// There are ten patterns before the ` | ` and four digits after it
+    WrongCount { at: Position, expected: usize, found: usize },
+    /// A pattern lights some of the segments `a` to `g`, each at most once
+    NotAPattern { at: Position, found: String },
+    /// No wiring of the segments makes the patterns the ten digits and
+    /// the output digits some of them
+    Unsolvable { line: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NoDivider { line } => {
+                write!(f, "line {line}: expected ten patterns, ` | ` and four digits, found no ` | `")
+            }
+            ParseError::WrongCount { at, expected, found } => {
+                write!(f, "{at}: expected {expected} patterns, found {found}")
+            }
+            ParseError::NotAPattern { at, found } => {
+                write!(f, "{at}: expected some of the segments `a` to `g`, found `{found}`")
+            }
+            ParseError::Unsolvable { line } => {
+                write!(f, "line {line}: expected patterns some wiring makes digits of, found none")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One line of notes, worked out
+#[derive(Debug, Eq, PartialEq)]
+pub struct Note {
+    /// Which scrambled segment each real segment is wired to
+    pub key: HashMap<char, char>,
+    /// The four output digits, still scrambled
+    pub outputs: Vec<String>,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Note>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const DAY: u8 = 8;
+
+    /// The notes, each with the wiring its patterns give away
+    fn parse(input: &str) -> Result<Vec<Note>, ParseError> {
+        let mut notes = Vec::new();
+        for (i, line) in input.trim_end().lines().enumerate() {
+            let (patterns, outputs) = line.split_once(" | ").ok_or(ParseError::NoDivider { line: i + 1 })?;
+            let read = |side: &str, expected: usize| {
+                let side_patterns: Vec<&str> = side.split_whitespace().collect();
+                if side_patterns.len() != expected {
+                    return Err(ParseError::WrongCount {
+                        at: Position::of(i, line, side),
+                        found: side_patterns.len(),
+                        expected,
+                    });
+                }
+                for pattern in &side_patterns {
+                    let segments: HashSet<char> = pattern.chars().collect();
+                    if segments.len() != pattern.len() || !segments.iter().all(|c| ('a'..='g').contains(c)) {
+                        return Err(ParseError::NotAPattern {
+                            at: Position::of(i, line, pattern),
+                            found: pattern.to_string(),
+                        });
+                    }
+                }
+                Ok(side_patterns.into_iter().map(str::to_string).collect::<Vec<_>>())
+            };
+            let (patterns, outputs) = (read(patterns, 10)?, read(outputs, 4)?);
+            let key = unscramble(&patterns.join(" "), HashMap::new(), 'a')
+                .map_err(|_| ParseError::Unsolvable { line: i + 1 })?;
+            if decode(&outputs, &key).is_none() {
+                return Err(ParseError::Unsolvable { line: i + 1 });
+            }
+            notes.push(Note { key, outputs });
+        }
+        Ok(notes)
+    }
+
+    fn part1(notes: &Vec<Note>) -> usize {
+        part1(notes)
+    }
+
+    // Parsing already did the recursive search for each key.
+    fn part2(notes: &Vec<Note>) -> usize {
+        part2(notes)
+    }
+}
+
+fn part1(notes: &[Note]) -> usize {
+    let mut count: usize = 0;
+    let is_1478 = |s: &String| {((s.len() == 2) | (s.len() == 3) | (s.len() == 4) | (s.len() == 7)) as usize};
+    for note in notes {
+        count += note.outputs.iter().map(is_1478).sum::<usize>();
+    }
+    count
+}
+
+fn part2(notes: &[Note]) -> usize {
+    let mut total = 0usize;
+    for note in notes {
+        total += decode(&note.outputs, &note.key).expect("parse checks every output is a digit");
+    }
+    total
+}
+
+fn like_digit(scrambled: &str, candidates: &HashMap<char, char>, n: usize) -> bool {
+    let d = DIGITS.get(&n).unwrap();
+    if scrambled.len() != d.len() {
+        return false;
+    }
+    for c in 'a'..='g' {
+        if let Some(cand) = candidates.get(&c) {
+            if scrambled.chars().any(|ch| ch == *cand) ^ d.contains(&c) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn like_digits(scrambled: &str, candidates: &HashMap<char, char>) -> bool {
+    (0..10).fold(
+        true, |acc, x| acc & scrambled.split(' ').fold(
+            false, |acc2, y| acc2 | like_digit(y, candidates, x)
+        )
+    )
+}
+
+fn unscramble(scrambled: &str, mut candidates: HashMap<char, char>,
+              letter: char) -> Result<HashMap<char, char>, HashMap<char, char>> {
+    for c in 'a'..='g' {
+        if candidates.values().any(|val| *val == c) { continue; }
+        candidates.insert(letter, c);
+        if like_digits(scrambled, &candidates) {
+            if letter == 'g' {
+                return Ok(candidates)
+            }
+            let next_letter = ((letter as u8) + 1) as char;
+            let res = unscramble(scrambled, candidates, next_letter);
+            match res {
+                Ok(set) => {
+                    return Ok(set);
+                },
+                Err(cands) => {
+                    candidates = cands;
+                    continue;
+                }
+            }
+        }
+    }
+    candidates.remove(&letter);
+    Err(candidates)
+}
+
+/// The number the scrambled digits show, or `None` if one isn't a digit
+fn decode(scrambled: &[impl AsRef<str>], candidates: &HashMap<char, char>) -> Option<usize> {
+    scrambled.iter().try_fold(
+        0, |acc, s| Some(10 * acc + (0..10).find(
+            |x| like_digit(s.as_ref(), candidates, *x)
+        )?)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_notes() {
+        let line = "egfdac gceb bcf cb gacefb gbcfa gacfe ecbafd efgbacd agbfd | cb  gceb bcf  gacefb";
+        assert_eq!(Day8::parse(line).unwrap()[0].outputs, ["cb", "gceb", "bcf", "gacefb"]);
+        assert_eq!(Day8::parse("abc def"), Err(ParseError::NoDivider { line: 1 }));
+        assert_eq!(
+            Day8::parse("ab | cd"),
+            Err(ParseError::WrongCount { at: Position { line: 1, column: 1 }, expected: 10, found: 1 })
+        );
+        assert_eq!(
+            Day8::parse("egfdac gceb bcf cb gacefb gbcfa gacfe ecbafd efgbacd agbfx | cb cb cb cb"),
+            Err(ParseError::NotAPattern { at: Position { line: 1, column: 54 }, found: "agbfx".to_string() })
+        );
+        assert_eq!(
+            Day8::parse("egfdac gceb bcf cb gacefb gbcfa gacfe ecbafd efgbacd agbfd | cb cb cb ab"),
+            Err(ParseError::Unsolvable { line: 1 })
+        );
+        assert_eq!(Day8::parse("a b c d e f g ab ac ad | a b c d"), Err(ParseError::Unsolvable { line: 1 }));
+    }
+
+    #[test]
+    fn is_like() {
+        let cands: HashMap<char, char> = HashMap::from([
+            ('c','a'),
+            ('f','b'),
+        ]);
+        let scram = "ab";
+
+        assert!(like_digit(scram, &cands, 1));
+    }
+
+    #[test]
+    fn is_not_like() {
+        let cands: HashMap<char, char> = HashMap::from([
+            ('c','a'),
+            ('f','b'),
+        ]);
+        let scram = "ae";
+
+        assert!(!like_digit(scram, &cands, 1));
+    }
+
+    #[test]
+    fn is_mostly_like() {
+        let cands: HashMap<char, char> = HashMap::from([
+            ('c','a'),
+            ('f','b'),
+        ]);
+        let scram = "abc";
+        
+        assert!(like_digit(scram, &cands, 7));
+    }
+
+    #[test]
+    fn are_like() {
+        let s = "egfdac gceb bcf cb gacefb gbcfa gacfe ecbafd efgbacd agbfd";
+        assert!(like_digits(s, &HashMap::new()));
+    }
+
+    #[test]
+    fn can_unscramble() {
+        let s = "egfdac gceb bcf cb gacefb gbcfa gacfe ecbafd efgbacd agbfd";
+        let res = unscramble(s, HashMap::new(), 'a');
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn can_decode() {
+        let s = "fceabd decba debgf acgefb cfedb ecf fdgaceb acfd fc gdbaec | cedbag cdeabf fdca bgadec";
+        let key: Vec<&str> = s.split(" | ").collect();
+        let cands = unscramble(key[0], HashMap::new(), 'a').unwrap();
+        let score = decode(&key[1].split(' ').collect::<Vec<_>>(), &cands);
+        assert_eq!(score, Some(6946));
+    }
+}
@@ -0,0 +1,92 @@
+use common::Solution;
+use grid::Grid;
+
+pub type Map = Grid<u8>;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Map;
+    type Error = grid::ParseError;
+    type Answer1 = u32;
+    type Answer2 = u128;
+    const DAY: u8 = 9;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("../example.txt"));
+
+    fn parse(raw: &str) -> Result<Map, grid::ParseError> {
+        raw.parse()
+    }
+
+    fn part1(map: &Map) -> u32 {
+        part1(map)
+    }
+
+    fn part2(map: &Map) -> u128 {
+        part2(map)
+    }
+}
+
+fn part1(map: &Map) -> u32 {
+    let mut risk: u32 = 0;
+    for pos in map.positions() {
+        if is_low_point(pos, map) {
+            risk += map[pos] as u32 + 1;
+        }
+    }
+    risk
+}
+
+fn part2(map: &Map) -> u128 {
+    let mut basins: Grid<u32> = Grid::new(map.width(), map.height(), 0);
+    let mut n_basins = 0u32;
+
+    // Every height other than 9 belongs to exactly one basin
+    for pos in map.positions() {
+        if basins[pos] != 0 || map[pos] == 9 {
+            continue
+        }
+        n_basins += 1;
+        explore_basin(pos, map, &mut basins, n_basins);
+    }
+
+    let mut basin_sizes: Vec<u32> = vec![0; n_basins as usize];
+    for &basin in basins.iter() {
+        if basin != 0 {
+            basin_sizes[(basin - 1) as usize] += 1
+        }
+    }
+    basin_sizes.sort();
+    basin_sizes.reverse();
+    // With fewer than three basins, multiply however many there are
+    basin_sizes.iter().take(3).fold(1, |a,b| a* (*b as u128))
+}
+
+fn explore_basin(pos: (usize, usize), map: &Map,
+                 basins: &mut Grid<u32>, n: u32) {
+
+    if map[pos] == 9 || basins[pos] != 0 {
+        return
+    }
+    
+    basins[pos] = n;
+
+    for neighbor in map.neighbors4(pos) {
+        explore_basin(neighbor, map, basins, n);
+    }
+}
+
+fn is_low_point(pos: (usize, usize), map: &Map) -> bool {
+    map.neighbors4(pos).all(|neighbor| map[pos] < map[neighbor])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn few_basins() {
+        assert_eq!(Day9::part2(&Day9::parse("19").unwrap()), 1);
+        assert_eq!(Day9::part2(&Day9::parse("191\n191").unwrap()), 4);
+        assert_eq!(Day9::part2(&Day9::parse("99").unwrap()), 1);
+    }
+}
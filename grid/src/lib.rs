@@ -0,0 +1,314 @@
+//! A rectangle of cells for the days whose input is a map of some sort.
+//! Cells are stored row after row in one `Vec` and addressed by
+//! `(row, col)`, with `(0, 0)` in the top left corner.
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use common::Position;
+
+const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    ( 0, -1),          ( 0, 1),
+    ( 1, -1), ( 1, 0), ( 1, 1),
+];
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { cells: vec![value; width * height], width, height }
+    }
+
+    /// A `width` by `height` grid where each cell is `f((row, col))`
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = positions(width, height).map(&mut f).collect();
+        Grid { cells, width, height }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(row, col)` is inside the grid
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        self.contains(pos).then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// Every cell, row by row
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every `(row, col)` in the grid, in the same order as [`Grid::iter`]
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        positions(self.width, self.height)
+    }
+
+    /// The up to 4 cells that share an edge with `pos`
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(pos, &NEIGHBORS4)
+    }
+
+    /// The up to 8 cells that share an edge or a corner with `pos`
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(pos, &NEIGHBORS8)
+    }
+
+    fn offsets(
+        &self,
+        (row, col): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(d_row, d_col)| {
+            let row = row.checked_add_signed(d_row).filter(|&row| row < height)?;
+            let col = col.checked_add_signed(d_col).filter(|&col| col < width)?;
+            Some((row, col))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+
+    /// Builds a grid out of numbered lines of text, one cell per character.
+    /// The numbers are the lines' indices in the whole input (counting from
+    /// 0), so errors point at the right line even when the grid is only part
+    /// of the input.
+    pub fn from_lines<'a>(
+        lines: impl IntoIterator<Item = (usize, &'a str)>,
+        expected: &'static str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (index, line) in lines {
+            let start = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or(ParseError::NotACell {
+                    at: Position { line: index + 1, column: column + 1 },
+                    expected,
+                    found: c,
+                })?);
+            }
+            let found = cells.len() - start;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(ParseError::Ragged { line: index + 1, expected, found })
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid { cells, width, height }),
+            _ => Err(ParseError::Empty),
+        }
+    }
+}
+
+fn positions(width: usize, height: usize) -> impl Iterator<Item = (usize, usize)> {
+    (0..height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!("{pos:?} is outside of a {}x{} grid", self.width, self.height)
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| {
+            panic!("{pos:?} is outside of a {width}x{height} grid")
+        })
+    }
+}
+
+/// Mostly for tests, where writing the rows out is the clearest
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "the rows have different lengths");
+        Grid { cells: rows.into_iter().flatten().collect(), width, height }
+    }
+}
+
+/// Something a grid can be parsed into and printed out of, one character
+/// per cell.
+pub trait Cell: Sized {
+    /// What a cell looks like in the input, for parse errors
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+/// Single digits, like heights or risk levels
+impl Cell for u8 {
+    const EXPECTED: &'static str = "a digit";
+
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|d| d as u8)
+    }
+
+    fn to_char(&self) -> char {
+        char::from_digit(*self as u32, 10).unwrap_or('+')
+    }
+}
+
+/// `#` for true and `.` for false
+impl Cell for bool {
+    const EXPECTED: &'static str = "`#` or `.`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Grid::from_lines(s.trim_end().lines().enumerate(), T::EXPECTED, T::from_char)
+    }
+}
+
+/// The rows on their own lines, without a newline after the last one
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Why some text is not a grid
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    NotACell { at: Position, expected: &'static str, found: char },
+    /// A row that is not as wide as the first one
+    Ragged { line: usize, expected: usize, found: usize },
+    Empty,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::NotACell { at, expected, found } => {
+                write!(f, "{at}: expected {expected}, found `{found}`")
+            }
+            ParseError::Ragged { line, expected, found } => {
+                write!(f, "line {line}: expected {expected} cells like the first row, found {found}")
+            }
+            ParseError::Empty => write!(f, "expected a grid, found nothing"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::new(3, 2, 0u8);
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors4((1, 1)).collect::<Vec<_>>(), [(0, 1), (1, 0), (1, 2)]);
+        assert_eq!(
+            grid.neighbors8((0, 2)).collect::<Vec<_>>(),
+            [(0, 1), (1, 1), (1, 2)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let text = "#..#\n.##.";
+        let grid: Grid<bool> = text.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        assert!(grid[(1, 2)]);
+        assert!(!grid[(1, 3)]);
+        assert_eq!(grid.to_string(), text);
+
+        let grid: Grid<u8> = "123\n456\n".parse().unwrap();
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.rows().map(<[u8]>::to_vec).collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "123\n4x6".parse::<Grid<u8>>(),
+            Err(ParseError::NotACell {
+                at: Position { line: 2, column: 2 },
+                expected: "a digit",
+                found: 'x'
+            })
+        );
+        assert_eq!(
+            "123\n45".parse::<Grid<u8>>(),
+            Err(ParseError::Ragged { line: 2, expected: 3, found: 2 })
+        );
+        assert_eq!("".parse::<Grid<u8>>(), Err(ParseError::Empty));
+    }
+}